@@ -145,7 +145,9 @@ impl FileSystem for MyFileSystem {
             &PathBuf::from("/disk"),
             false,
             true,
-        )])
+        )
+        .with_space(64 * 1024 * 1024 * 1024, 16 * 1024 * 1024 * 1024)
+        .with_file_system("fakefs")])
     }
 
     fn user_dirs(&self, _canonicalize_paths: bool) -> Option<egui_file_dialog::UserDirectories> {
@@ -8,6 +8,7 @@ struct MyApp {
 
     picked_directory: Option<PathBuf>,
     picked_file: Option<PathBuf>,
+    picked_file_or_directory: Option<PathBuf>,
     picked_multiple: Option<Vec<PathBuf>>,
     saved_file: Option<PathBuf>,
 }
@@ -45,6 +46,7 @@ impl MyApp {
 
             picked_directory: None,
             picked_file: None,
+            picked_file_or_directory: None,
             picked_multiple: None,
             saved_file: None,
         }
@@ -79,6 +81,14 @@ impl eframe::App for MyApp {
             }
             ui.label(format!("Selected file: {:?}", self.picked_file));
 
+            if ui.button("Pick file or directory").clicked() {
+                self.file_dialog.pick_file_or_directory();
+            }
+            ui.label(format!(
+                "Picked file or directory: {:?}",
+                self.picked_file_or_directory
+            ));
+
             if ui.button("Pick multiple").clicked() {
                 self.file_dialog.pick_multiple();
             }
@@ -105,6 +115,7 @@ impl eframe::App for MyApp {
                 match self.file_dialog.mode() {
                     DialogMode::PickDirectory => self.picked_directory = Some(path),
                     DialogMode::PickFile => self.picked_file = Some(path),
+                    DialogMode::PickFileOrDirectory => self.picked_file_or_directory = Some(path),
                     DialogMode::SaveFile => self.saved_file = Some(path),
                     DialogMode::PickMultiple => {}
                 }
@@ -0,0 +1,486 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use super::{FileDialogConfig, Filter, MimeDetection};
+use crate::{FileSystem, MimeDatabase};
+
+/// Declarative description of a file filter.
+///
+/// Unlike a filter function, a `FileFilterSpec` can be serialized, for example to load
+/// file filters from the configuration files of an application.
+/// Use `FileDialog::add_file_filter_spec` to add the filter to the dialog.
+///
+/// # Examples
+///
+/// ```
+/// use egui_file_dialog::{FileDialog, FileFilterSpec};
+///
+/// // Images that are not larger than 10 MB
+/// let spec = FileFilterSpec::And(vec![
+///     FileFilterSpec::extensions(&["png", "jpg"]),
+///     FileFilterSpec::Size {
+///         min: None,
+///         max: Some(10 * 1024 * 1024),
+///     },
+/// ]);
+///
+/// FileDialog::new().add_file_filter_spec("Images", spec);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FileFilterSpec {
+    /// Matches files with one of the given extensions, without the leading dot.
+    /// The comparison is case-insensitive.
+    Extensions(Vec<String>),
+    /// Matches files whose name matches one of the given glob patterns, like `*.tar.gz`.
+    /// `*` matches any number of characters and `?` matches a single character.
+    /// The comparison is case-insensitive.
+    Globs(Vec<String>),
+    /// Matches files with one of the given MIME types. Wildcards like `image/*` are supported.
+    MimeTypes(Vec<String>),
+    /// Matches files whose size in bytes is within the given range. Both bounds are inclusive.
+    Size {
+        /// Minimum size of the file in bytes.
+        min: Option<u64>,
+        /// Maximum size of the file in bytes.
+        max: Option<u64>,
+    },
+    /// Matches files that were last modified within the given range.
+    ///
+    /// The bounds are serialized as RFC 3339 timestamps in UTC, like `2024-05-01T12:30:00Z`.
+    Modified {
+        /// Files modified before this point in time do not match.
+        #[cfg_attr(feature = "serde", serde(default, with = "rfc3339"))]
+        after: Option<SystemTime>,
+        /// Files modified after this point in time do not match.
+        #[cfg_attr(feature = "serde", serde(default, with = "rfc3339"))]
+        before: Option<SystemTime>,
+    },
+    /// Matches files that were last modified within the given duration before now.
+    ///
+    /// The duration is serialized as a number of whole seconds.
+    ModifiedWithin(#[cfg_attr(feature = "serde", serde(with = "seconds"))] Duration),
+    /// Matches if all the given filters match.
+    And(Vec<Self>),
+    /// Matches if any of the given filters match.
+    Or(Vec<Self>),
+    /// Matches if the given filter does not match.
+    Not(Box<Self>),
+}
+
+impl FileFilterSpec {
+    /// Creates a filter matching the given file extensions.
+    pub fn extensions(extensions: &[&str]) -> Self {
+        Self::Extensions(extensions.iter().map(|e| (*e).to_string()).collect())
+    }
+
+    /// Creates a filter matching the given glob patterns.
+    pub fn globs(globs: &[&str]) -> Self {
+        Self::Globs(globs.iter().map(|g| (*g).to_string()).collect())
+    }
+
+    /// Creates a filter matching the given MIME types.
+    pub fn mime_types(mime_types: &[&str]) -> Self {
+        Self::MimeTypes(mime_types.iter().map(|m| (*m).to_string()).collect())
+    }
+
+    /// Returns the name patterns of the filter, like `*.png` or `image/*`, that can be
+    /// displayed to the user.
+    /// Patterns inside a `Not` filter as well as size and date ranges are not included.
+    pub fn patterns(&self) -> Vec<String> {
+        let mut patterns = Vec::new();
+        self.collect_patterns(&mut patterns);
+        patterns
+    }
+
+    fn collect_patterns(&self, patterns: &mut Vec<String>) {
+        match self {
+            Self::Extensions(extensions) => {
+                patterns.extend(extensions.iter().map(|e| format!("*.{e}")));
+            }
+            Self::Globs(globs) => patterns.extend(globs.iter().cloned()),
+            Self::MimeTypes(mime_types) => patterns.extend(mime_types.iter().cloned()),
+            Self::And(specs) | Self::Or(specs) => {
+                for spec in specs {
+                    spec.collect_patterns(patterns);
+                }
+            }
+            Self::Size { .. } | Self::Modified { .. } | Self::ModifiedWithin(_) | Self::Not(_) => {}
+        }
+    }
+
+    /// Compiles the specification to a filter function.
+    ///
+    /// The file system, MIME database and MIME detection mode of the given configuration
+    /// are used to query the metadata and MIME type of a path.
+    /// If MIME detection is disabled, the file extension is used to detect the MIME type.
+    ///
+    /// The returned function keeps using the file system and MIME database that are set
+    /// when it is compiled. The file dialog itself uses `FileFilterSpec::matches_config`
+    /// with its current configuration instead.
+    pub fn compile(&self, config: &FileDialogConfig) -> Filter<Path> {
+        let spec = self.clone();
+        let file_system = config.file_system.clone();
+        let mime_database = config.mime_database.clone();
+        let mime_detection = filter_mime_detection(config.mime_detection);
+
+        Arc::new(move |path| spec.matches(path, &*file_system, &mime_database, mime_detection))
+    }
+
+    /// Checks if the given path matches the specification, using the file system,
+    /// MIME database and MIME detection mode of the given configuration.
+    /// If MIME detection is disabled, the file extension is used to detect the MIME type.
+    pub fn matches_config(&self, path: &Path, config: &FileDialogConfig) -> bool {
+        self.matches(
+            path,
+            &*config.file_system,
+            &config.mime_database,
+            filter_mime_detection(config.mime_detection),
+        )
+    }
+
+    /// Checks if the given path matches the specification.
+    pub fn matches(
+        &self,
+        path: &Path,
+        file_system: &dyn FileSystem,
+        mime_database: &MimeDatabase,
+        mime_detection: MimeDetection,
+    ) -> bool {
+        match self {
+            Self::Extensions(extensions) => {
+                let extension = path
+                    .extension()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_lowercase();
+
+                extensions.iter().any(|e| e.to_lowercase() == extension)
+            }
+            Self::Globs(globs) => {
+                let file_name = path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_lowercase();
+
+                globs
+                    .iter()
+                    .any(|g| glob_matches(&g.to_lowercase(), &file_name))
+            }
+            Self::MimeTypes(mime_types) => mime_database
+                .detect(path, file_system, mime_detection)
+                .is_some_and(|m| mime_types.iter().any(|pattern| m.matches(pattern))),
+            Self::Size { min, max } => {
+                let Some(size) = file_system.metadata(path).ok().and_then(|m| m.size) else {
+                    return false;
+                };
+
+                min.is_none_or(|min| size >= min) && max.is_none_or(|max| size <= max)
+            }
+            Self::Modified { after, before } => {
+                let Some(modified) = file_system
+                    .metadata(path)
+                    .ok()
+                    .and_then(|m| m.last_modified)
+                else {
+                    return false;
+                };
+
+                after.is_none_or(|after| modified >= after)
+                    && before.is_none_or(|before| modified <= before)
+            }
+            Self::ModifiedWithin(duration) => file_system
+                .metadata(path)
+                .ok()
+                .and_then(|m| m.last_modified)
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .is_some_and(|age| age <= *duration),
+            Self::And(specs) => specs
+                .iter()
+                .all(|s| s.matches(path, file_system, mime_database, mime_detection)),
+            Self::Or(specs) => specs
+                .iter()
+                .any(|s| s.matches(path, file_system, mime_database, mime_detection)),
+            Self::Not(spec) => !spec.matches(path, file_system, mime_database, mime_detection),
+        }
+    }
+}
+
+/// Serializes an optional point in time as RFC 3339 timestamp.
+#[cfg(feature = "serde")]
+mod rfc3339 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::data::date;
+
+    #[allow(clippy::ref_option)] // Signature required by `serde(with)`
+    pub fn serialize<S: Serializer>(
+        time: &Option<SystemTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => {
+                // Only write the fraction of a second if there is one
+                let subsec_digits = time.duration_since(UNIX_EPOCH).map_or(9, |d| {
+                    if d.subsec_nanos() == 0 {
+                        0
+                    } else {
+                        9
+                    }
+                });
+
+                serializer.serialize_some(&date::format_rfc3339(*time, subsec_digits))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<SystemTime>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| {
+                date::parse_rfc3339(&text)
+                    .ok_or_else(|| D::Error::custom(format!("invalid RFC 3339 timestamp: {text}")))
+            })
+            .transpose()
+    }
+}
+
+/// Serializes a duration as a number of whole seconds.
+#[cfg(feature = "serde")]
+mod seconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+/// Returns the MIME detection mode used by file filters. Filters matching MIME types
+/// fall back to the file extension if MIME detection is disabled.
+const fn filter_mime_detection(detection: MimeDetection) -> MimeDetection {
+    match detection {
+        MimeDetection::Disabled => MimeDetection::Extension,
+        detection => detection,
+    }
+}
+
+/// Checks if the text matches the glob pattern.
+/// Supports `*` to match any number of characters and `?` to match a single character.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it was matched at.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                let Some((star_p, star_t)) = backtrack else {
+                    return false;
+                };
+
+                // Let the last `*` match one more character and try again.
+                p = star_p + 1;
+                t = star_t + 1;
+                backtrack = Some((star_p, star_t + 1));
+            }
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io;
+
+    use super::*;
+    use crate::config::FileFilter;
+    use crate::test_util::TempDir;
+
+    /// Creates a temporary directory with files of the given names and sizes.
+    fn temp_files(name: &str, files: &[(&str, u64)]) -> io::Result<TempDir> {
+        let dir = TempDir::new(name)?;
+
+        for (file, size) in files {
+            File::create(dir.join(file))?.set_len(*size)?;
+        }
+
+        Ok(dir)
+    }
+
+    #[test]
+    fn glob_wildcards() {
+        assert!(glob_matches("*.tar.gz", "backup.tar.gz"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("a*b*c", "aXXbYYbc"));
+        assert!(!glob_matches("*.tar.gz", "backup.tar"));
+
+        assert!(glob_matches("file?.txt", "file1.txt"));
+        assert!(!glob_matches("file?.txt", "file.txt"));
+        assert!(!glob_matches("file?.txt", "file12.txt"));
+
+        // The comparison itself is case-sensitive, `Globs` lowercases both sides
+        assert!(!glob_matches("*.PNG", "image.png"));
+
+        let config = FileDialogConfig::default();
+        let spec = FileFilterSpec::globs(&["*.PNG"]);
+        assert!(spec.matches_config(Path::new("/tmp/IMAGE.png"), &config));
+        assert!(!spec.matches_config(Path::new("/tmp/image.jpg"), &config));
+    }
+
+    #[test]
+    fn size_bounds() -> io::Result<()> {
+        let dir = temp_files(
+            "filter-size",
+            &[("small", 10), ("medium", 100), ("large", 1000)],
+        )?;
+        let config = FileDialogConfig::default();
+
+        let spec = FileFilterSpec::Size {
+            min: Some(100),
+            max: Some(999),
+        };
+        assert!(!spec.matches_config(&dir.join("small"), &config));
+        assert!(spec.matches_config(&dir.join("medium"), &config));
+        assert!(!spec.matches_config(&dir.join("large"), &config));
+
+        let spec = FileFilterSpec::Size {
+            min: None,
+            max: Some(10),
+        };
+        assert!(spec.matches_config(&dir.join("small"), &config));
+        assert!(!spec.matches_config(&dir.join("missing"), &config));
+
+        Ok(())
+    }
+
+    #[test]
+    fn date_bounds() -> io::Result<()> {
+        let dir = temp_files("filter-date", &[("old", 0), ("new", 0)])?;
+        let config = FileDialogConfig::default();
+
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let old_time = now - 10 * day;
+
+        File::options()
+            .write(true)
+            .open(dir.join("old"))?
+            .set_modified(old_time)?;
+
+        let spec = FileFilterSpec::Modified {
+            after: Some(now - day),
+            before: None,
+        };
+        assert!(!spec.matches_config(&dir.join("old"), &config));
+        assert!(spec.matches_config(&dir.join("new"), &config));
+
+        let spec = FileFilterSpec::Modified {
+            after: Some(old_time),
+            before: Some(now - day),
+        };
+        assert!(spec.matches_config(&dir.join("old"), &config));
+        assert!(!spec.matches_config(&dir.join("new"), &config));
+
+        let spec = FileFilterSpec::ModifiedWithin(day);
+        assert!(!spec.matches_config(&dir.join("old"), &config));
+        assert!(spec.matches_config(&dir.join("new"), &config));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() -> serde_json::Result<()> {
+        let spec = FileFilterSpec::And(vec![
+            FileFilterSpec::extensions(&["png"]),
+            FileFilterSpec::Modified {
+                after: SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(28_576_110 * 60)),
+                before: None,
+            },
+            FileFilterSpec::Not(Box::new(FileFilterSpec::ModifiedWithin(
+                Duration::from_secs(60 * 60),
+            ))),
+        ]);
+
+        let json = serde_json::to_string(&spec)?;
+        assert_eq!(
+            json,
+            r#"{"and":[{"extensions":["png"]},{"modified":{"after":"2024-05-01T12:30:00Z","before":null}},{"not":{"modified_within":3600}}]}"#
+        );
+        assert_eq!(serde_json::from_str::<FileFilterSpec>(&json)?, spec);
+
+        // Missing bounds, offsets and fractions of a second
+        let spec: FileFilterSpec =
+            serde_json::from_str(r#"{"modified":{"before":"2024-05-01T14:30:00.5+02:00"}}"#)?;
+        assert_eq!(
+            spec,
+            FileFilterSpec::Modified {
+                after: None,
+                before: SystemTime::UNIX_EPOCH
+                    .checked_add(Duration::from_millis(1_714_566_600_500)),
+            }
+        );
+
+        assert!(
+            serde_json::from_str::<FileFilterSpec>(r#"{"modified":{"after":"yesterday"}}"#)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() {
+        let filter = |spec| FileFilter {
+            id: egui::Id::new("filter"),
+            name: "Images".to_string(),
+            filter: Arc::new(|_: &Path| true),
+            spec,
+        };
+
+        assert_eq!(
+            filter(Some(FileFilterSpec::Or(vec![
+                FileFilterSpec::extensions(&["png", "jpg"]),
+                FileFilterSpec::mime_types(&["image/*"]),
+                FileFilterSpec::Not(Box::new(FileFilterSpec::globs(&["*.tmp"]))),
+            ])))
+            .to_string(),
+            "Images (*.png, *.jpg, image/*)"
+        );
+        assert_eq!(
+            filter(Some(FileFilterSpec::Size {
+                min: None,
+                max: Some(10)
+            }))
+            .to_string(),
+            "Images"
+        );
+        assert_eq!(filter(None).to_string(), "Images");
+    }
+}
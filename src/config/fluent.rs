@@ -0,0 +1,230 @@
+//! Minimal parser of Fluent translation files, supporting the subset of the syntax
+//! required for the labels of the file dialog.
+//!
+//! See <https://projectfluent.org/fluent/guide/> for the syntax.
+
+use super::labels::find_closing_brace;
+
+/// Parses the messages of a Fluent file. Returns the ID and the value of each message.
+///
+/// Supported are single and multiline text, variables like `{ $count }`, which are
+/// converted to the `{count}` placeholders used by the labels, string literals
+/// like `{ " " }` and select expressions with the `one` and `other` variants, which
+/// are converted to the plural forms `{count|singular|plural}`.
+/// Comments, terms and attributes are skipped.
+///
+/// The labels only support two plural forms, see `FileDialogLabels::format`.
+/// The variants of other plural categories, like `[zero]`, `[two]`, `[few]` and `[many]`,
+/// are ignored, so that the `other` variant is used for these values.
+pub fn parse(content: &str) -> Vec<(String, String)> {
+    let mut messages: Vec<(String, Vec<&str>)> = Vec::new();
+    // If the previous line belongs to a message that can be continued
+    let mut in_message = false;
+
+    for line in content.lines() {
+        let is_continuation = (line.starts_with([' ', '\t']) && !line.trim().is_empty())
+            || line.starts_with(['}', '[', '*']);
+
+        if is_continuation {
+            // Attributes of a message are not supported
+            if line.trim_start().starts_with('.') {
+                in_message = false;
+            }
+
+            if in_message {
+                if let Some((_, lines)) = messages.last_mut() {
+                    lines.push(line.trim());
+                }
+            }
+
+            continue;
+        }
+
+        in_message = false;
+
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+            continue;
+        }
+
+        let Some((id, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let id = id.trim();
+        if id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            continue;
+        }
+
+        messages.push((id.to_string(), vec![value.trim()]));
+        in_message = true;
+    }
+
+    messages
+        .into_iter()
+        .map(|(id, mut lines)| {
+            if lines.first().is_some_and(|l| l.is_empty()) {
+                lines.remove(0);
+            }
+
+            (id, parse_pattern(&lines.join("\n")))
+        })
+        .collect()
+}
+
+/// Converts the placeables of a pattern to the syntax used by the labels.
+fn parse_pattern(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = find_closing_brace(rest) else {
+            break;
+        };
+
+        let expression = rest[1..end].trim();
+
+        if let Some(select) = parse_select(expression) {
+            result.push_str(&select);
+        } else if let Some(variable) = expression.strip_prefix('$') {
+            result.push('{');
+            result.push_str(variable);
+            result.push('}');
+        } else if let Some(literal) = expression
+            .strip_prefix('"')
+            .and_then(|e| e.strip_suffix('"'))
+        {
+            result.push_str(&literal.replace("\\\"", "\"").replace("\\\\", "\\"));
+        } else {
+            // Unsupported expressions are kept as they are
+            result.push_str(&rest[..=end]);
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Converts a select expression like `$count -> [one] One item *[other] { $count } items`
+/// to the plural forms used by the labels.
+///
+/// Variants other than `one`, `1`, `other` and the default variant are ignored,
+/// as the labels only support two plural forms.
+///
+/// Returns None if the expression is not a select expression on a variable.
+fn parse_select(expression: &str) -> Option<String> {
+    let (selector, variants) = expression.split_once("->")?;
+    let variable = selector.trim().strip_prefix('$')?;
+
+    let mut singular = None;
+    let mut plural = None;
+
+    for variant in variants.lines().map(str::trim) {
+        let is_default = variant.starts_with('*');
+        let Some((key, pattern)) = variant
+            .trim_start_matches('*')
+            .strip_prefix('[')
+            .and_then(|v| v.split_once(']'))
+        else {
+            continue;
+        };
+
+        let pattern = parse_pattern(pattern.trim());
+
+        match key.trim() {
+            "one" | "1" => singular = Some(pattern),
+            "other" => plural = Some(pattern),
+            _ if is_default => plural = Some(pattern),
+            _ => {}
+        }
+    }
+
+    let plural = plural.or_else(|| singular.clone())?;
+    let singular = singular.unwrap_or_else(|| plural.clone());
+
+    Some(format!("{{{variable}|{singular}|{plural}}}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_messages() {
+        let messages = parse(
+            "# Comment\n\
+             -brand = Term\n\
+             title-save-file = 📥 Save File\n\
+             \x20   .tooltip = Attribute\n\
+             \x20   continued attribute\n\
+             show_hidden = {\" \"}Show hidden\n\
+             err-too-few-items = Select at least { $count } items\n\
+             multiline =\n    First line\n    second line\n\
+             selected = { $count ->\n    [one] One item\n   *[other] { $count } items\n} selected\n\
+             \n\
+             invalid line\n",
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                ("title-save-file".to_string(), "📥 Save File".to_string()),
+                ("show_hidden".to_string(), " Show hidden".to_string()),
+                (
+                    "err-too-few-items".to_string(),
+                    "Select at least {count} items".to_string()
+                ),
+                (
+                    "multiline".to_string(),
+                    "First line\nsecond line".to_string()
+                ),
+                (
+                    "selected".to_string(),
+                    "{count|One item|{count} items} selected".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_unsupported_plural_categories() {
+        let messages = parse(
+            "items = { $count ->\n\
+             \x20   [zero] No items\n\
+             \x20   [one] One item\n\
+             \x20   [two] Two items\n\
+             \x20   [few] { $count } items (few)\n\
+             \x20   [many] { $count } items (many)\n\
+             \x20  *[other] { $count } items\n\
+             }\n\
+             zero = { $count ->\n\
+             \x20   [zero] No items\n\
+             \x20  *[many] { $count } items\n\
+             }\n",
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "items".to_string(),
+                    "{count|One item|{count} items}".to_string()
+                ),
+                // The default variant is used as plural form
+                (
+                    "zero".to_string(),
+                    "{count|{count} items|{count} items}".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -1,7 +1,30 @@
+use crate::UserDirectory;
+
+use super::fluent;
+
+/// Languages that are written from right to left.
+const RIGHT_TO_LEFT_LANGUAGES: &[&str] = &[
+    "ar", "dv", "fa", "he", "ks", "ku", "ps", "sd", "ug", "ur", "yi",
+];
+
+/// Languages that use the singular form for 0 as well as for 1, like French.
+const ZERO_IS_SINGULAR_LANGUAGES: &[&str] = &["ff", "fr", "hy", "kab"];
+
+/// Translations of the labels that are bundled with the crate, as Fluent files.
+const BUNDLED_LOCALES: &[(&str, &str)] = &[
+    ("de", include_str!("locales/de.ftl")),
+    ("es", include_str!("locales/es.ftl")),
+    ("fr", include_str!("locales/fr.ftl")),
+];
+
 /// Contains the text labels that the file dialog uses.
 ///
 /// This is used to enable multiple language support.
 ///
+/// Some labels contain placeholders like `{count}`, which are replaced with a value
+/// when the label is displayed. Plural forms can be selected using the syntax
+/// `{count|singular|plural}`. See `FileDialogLabels::format` for details.
+///
 /// # Example
 ///
 /// The following example shows how the default title of the dialog can be displayed
@@ -19,14 +42,31 @@
 ///
 /// let file_dialog = FileDialog::new().labels(labels_german);
 /// ```
+///
+/// Translations for some languages are bundled with the crate and can be selected
+/// with `FileDialogLabels::for_locale`. Labels can also be loaded from Fluent files
+/// using `FileDialogLabels::from_fluent`, in which case the label names are written
+/// with hyphens, like `title-save-file`.
+///
+/// ```
+/// use egui_file_dialog::{FileDialog, FileDialogLabels};
+///
+/// let file_dialog = FileDialog::new().labels(FileDialogLabels::for_locale("fr"));
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct FileDialogLabels {
     // ------------------------------------------------------------------------
     // General:
+    /// Language code of the labels, like `en` or `ar-EG`.
+    /// Used to determine if the dialog is laid out from right to left.
+    pub locale: String,
+
     /// The default window title used when the dialog is in `DialogMode::SelectDirectory` mode.
     pub title_select_directory: String,
     /// The default window title used when the dialog is in `DialogMode::SelectFile` mode.
     pub title_select_file: String,
+    /// The default window title used when the dialog is in `DialogMode::PickFileOrDirectory` mode.
+    pub title_select_file_or_directory: String,
     /// The default window title used when the dialog is in `DialogMode::SelectMultiple` mode.
     pub title_select_multiple: String,
     /// The default window title used when the dialog is in `DialogMode::SaveFile` mode.
@@ -47,6 +87,36 @@ pub struct FileDialogLabels {
     pub show_hidden: String,
     /// Text used for the option to show or hide system files.
     pub show_system_files: String,
+    /// Text used for the option to show or hide the quick filter bar.
+    pub show_quick_filter: String,
+
+    // ------------------------------------------------------------------------
+    // Quick filter bar:
+    /// Text that appears in front of the modification date filter.
+    pub quick_filter_modified: String,
+    /// Text that appears in front of the size filter.
+    pub quick_filter_size: String,
+    /// Text displayed in the modification date dropdown if no date is filtered.
+    pub quick_filter_any_time: String,
+    /// Text displayed for the option to show items modified today.
+    pub quick_filter_today: String,
+    /// Text displayed for the option to show items modified in the last seven days.
+    pub quick_filter_last_seven_days: String,
+    /// Text displayed for the option to show items modified within a custom range.
+    pub quick_filter_custom: String,
+    /// Hint text of the minimum size input.
+    pub quick_filter_min_size: String,
+    /// Hint text of the maximum size input.
+    pub quick_filter_max_size: String,
+    /// Text of the chip displaying the active modification date filter.
+    /// `{range}` is replaced with the selected date range.
+    pub quick_filter_modified_chip: String,
+    /// Text of the chip displaying the active minimum size filter.
+    /// `{size}` is replaced with the minimum size.
+    pub quick_filter_min_size_chip: String,
+    /// Text of the chip displaying the active maximum size filter.
+    /// `{size}` is replaced with the maximum size.
+    pub quick_filter_max_size_chip: String,
 
     // ------------------------------------------------------------------------
     // Left panel:
@@ -54,10 +124,18 @@ pub struct FileDialogLabels {
     pub heading_pinned: String,
     /// Heading of the "Places" section in the left panel
     pub heading_places: String,
+    /// Heading of the "Bookmarks" section in the left panel, containing the bookmarks of
+    /// the desktop environment's file manager
+    pub heading_bookmarks: String,
+    /// Heading of the "Recent" section in the left panel, containing the recently used files
+    pub heading_recent: String,
     /// Heading of the "Devices" section in the left panel
     pub heading_devices: String,
     /// Heading of the "Removable Devices" section in the left panel
     pub heading_removable_devices: String,
+    /// Tooltip of a device in the left panel showing the space of the device.
+    /// `{available}` and `{total}` are replaced with the available and total space.
+    pub device_usage: String,
 
     /// Name of the home directory
     pub home_dir: String,
@@ -73,6 +151,10 @@ pub struct FileDialogLabels {
     pub pictures_dir: String,
     /// Name of the videos directory
     pub videos_dir: String,
+    /// Name of the templates directory
+    pub templates_dir: String,
+    /// Name of the public share directory
+    pub public_dir: String,
 
     // ------------------------------------------------------------------------
     // Central panel:
@@ -82,6 +164,22 @@ pub struct FileDialogLabels {
     pub unpin_folder: String,
     /// Text used for the option to rename a pinned folder.
     pub rename_pinned_folder: String,
+    /// Text used for the menu to move a pinned folder into another group.
+    pub move_pinned_folder_to_group: String,
+    /// Text used for the option to move a pinned folder out of its group.
+    pub pinned_folder_no_group: String,
+    /// Hint text of the input for the name of a new group of pinned folders.
+    pub new_pinned_folder_group: String,
+    /// Text used for the menu to change the icon and color of a pinned folder.
+    pub pinned_folder_appearance: String,
+    /// Text used for the option to reset the icon and color of a pinned folder.
+    pub reset_pinned_folder_appearance: String,
+    /// Text used for the option to unpin all folders that no longer exist.
+    pub remove_missing_pinned_folders: String,
+    /// Tooltip of a pinned folder that no longer exists.
+    pub pinned_folder_missing: String,
+    /// Text shown in the left panel while a folder is dragged and no folders are pinned.
+    pub drop_to_pin: String,
 
     // ------------------------------------------------------------------------
     // Bottom panel:
@@ -89,8 +187,26 @@ pub struct FileDialogLabels {
     pub selected_directory: String,
     /// Text that appears in front of the selected file preview in the bottom panel.
     pub selected_file: String,
+    /// Text that appears in front of the selected file or folder preview in the bottom panel.
+    pub selected_item: String,
     /// Text that appears in front of the selected items preview in the bottom panel.
+    /// `{count}` is replaced with the number of selected items.
     pub selected_items: String,
+    /// Summary of the selected items in the bottom panel.
+    /// `{files}` and `{folders}` are replaced with the number of selected files and folders,
+    /// `{size}` with the total size of the selection.
+    pub selection_summary: String,
+    /// Summary of the selected items in the bottom panel, used instead of
+    /// `selection_summary` if directory sizes are not calculated.
+    /// `{files}` and `{folders}` are replaced with the number of selected files and folders,
+    /// `{size}` with the total size of the selected files.
+    pub selection_summary_without_folder_sizes: String,
+    /// Heading of the list of all selected items in the bottom panel, including the items
+    /// selected in other directories. `{count}` is replaced with the number of items.
+    pub selection_basket: String,
+    /// Free space of the volume of the current directory displayed in the save dialog.
+    /// `{size}` is replaced with the available space.
+    pub free_space: String,
     /// Text that appears in front of the file name input in the bottom panel.
     pub file_name: String,
     /// Text displayed in the file filter dropdown for the "All Files" option.
@@ -100,6 +216,8 @@ pub struct FileDialogLabels {
 
     /// Button text to open the selected item.
     pub open_button: String,
+    /// Button text to pick the selected item in `DialogMode::PickFileOrDirectory` mode.
+    pub select_button: String,
     /// Button text to save the file.
     pub save_button: String,
     /// Button text to cancel the dialog.
@@ -107,7 +225,8 @@ pub struct FileDialogLabels {
 
     // ------------------------------------------------------------------------
     // Modal windows:
-    /// Text displayed after the path within the modal to overwrite the selected file.
+    /// Text displayed within the modal to overwrite the selected file.
+    /// `{name}` is replaced with the name of the file.
     pub overwrite_file_modal_text: String,
 
     // ------------------------------------------------------------------------
@@ -117,17 +236,32 @@ pub struct FileDialogLabels {
     /// Error if no file name was specified.
     pub err_empty_file_name: String,
     /// Error if the directory already exists.
+    /// `{name}` is replaced with the name entered by the user.
     pub err_directory_exists: String,
     /// Error if the file already exists.
+    /// `{name}` is replaced with the name entered by the user.
     pub err_file_exists: String,
+    /// Error if fewer items are selected than required.
+    /// `{count}` is replaced with the minimum number of items.
+    pub err_too_few_items: String,
+    /// Error if more items are selected than allowed.
+    /// `{count}` is replaced with the maximum number of items.
+    pub err_too_many_items: String,
+    /// Error if a directory is selected but only files can be selected.
+    pub err_only_files: String,
+    /// Error if a file is selected but only directories can be selected.
+    pub err_only_directories: String,
 }
 
 impl Default for FileDialogLabels {
     /// Creates a new object with the default english labels.
     fn default() -> Self {
         Self {
+            locale: "en".to_string(),
+
             title_select_directory: "📁 Select Folder".to_string(),
             title_select_file: "📂 Open File".to_string(),
+            title_select_file_or_directory: "🗁 Select File or Folder".to_string(),
             title_select_multiple: "🗐 Select Multiple".to_string(),
             title_save_file: "📥 Save File".to_string(),
 
@@ -138,11 +272,27 @@ impl Default for FileDialogLabels {
             working_directory: "↗  Go to working directory".to_string(),
             show_hidden: " Show hidden".to_string(),
             show_system_files: " Show system files".to_string(),
+            show_quick_filter: " Filter by date and size".to_string(),
+
+            quick_filter_modified: "Modified:".to_string(),
+            quick_filter_size: "Size:".to_string(),
+            quick_filter_any_time: "Any time".to_string(),
+            quick_filter_today: "Today".to_string(),
+            quick_filter_last_seven_days: "Last 7 days".to_string(),
+            quick_filter_custom: "Custom".to_string(),
+            quick_filter_min_size: "Min".to_string(),
+            quick_filter_max_size: "Max".to_string(),
+            quick_filter_modified_chip: "Modified: {range}".to_string(),
+            quick_filter_min_size_chip: "Size ≥ {size}".to_string(),
+            quick_filter_max_size_chip: "Size ≤ {size}".to_string(),
 
             heading_pinned: "Pinned".to_string(),
             heading_places: "Places".to_string(),
+            heading_bookmarks: "Bookmarks".to_string(),
+            heading_recent: "Recent".to_string(),
             heading_devices: "Devices".to_string(),
             heading_removable_devices: "Removable Devices".to_string(),
+            device_usage: "{available} free of {total}".to_string(),
 
             home_dir: "🏠  Home".to_string(),
             desktop_dir: "🖵  Desktop".to_string(),
@@ -151,28 +301,505 @@ impl Default for FileDialogLabels {
             audio_dir: "🎵  Audio".to_string(),
             pictures_dir: "🖼  Pictures".to_string(),
             videos_dir: "🎞  Videos".to_string(),
+            templates_dir: "📄  Templates".to_string(),
+            public_dir: "👥  Public".to_string(),
 
             pin_folder: "📌 Pin".to_string(),
             unpin_folder: "✖ Unpin".to_string(),
             rename_pinned_folder: "✏ Rename".to_string(),
+            move_pinned_folder_to_group: "🗀 Move to group".to_string(),
+            pinned_folder_no_group: "No group".to_string(),
+            new_pinned_folder_group: "New group".to_string(),
+            pinned_folder_appearance: "🎨 Icon and color".to_string(),
+            reset_pinned_folder_appearance: "⟲ Reset".to_string(),
+            remove_missing_pinned_folders: "🗑 Remove missing pins".to_string(),
+            pinned_folder_missing: "The folder no longer exists".to_string(),
+            drop_to_pin: "Drop here to pin".to_string(),
 
             selected_directory: "Selected directory:".to_string(),
             selected_file: "Selected file:".to_string(),
-            selected_items: "Selected items:".to_string(),
+            selected_item: "Selected item:".to_string(),
+            selected_items: "{count|One item|{count} items} selected:".to_string(),
+            selection_summary:
+                "{files|1 file|{files} files}, {folders|1 folder|{folders} folders}, {size} total"
+                    .to_string(),
+            selection_summary_without_folder_sizes:
+                "{files|1 file|{files} files} ({size}), {folders|1 folder|{folders} folders}"
+                    .to_string(),
+            selection_basket: "Selection ({count})".to_string(),
+            free_space: "{size} free".to_string(),
             file_name: "File name:".to_string(),
             file_filter_all_files: "All Files".to_string(),
             save_extension_any: "Any".to_string(),
 
             open_button: "🗀  Open".to_string(),
+            select_button: "✔  Select".to_string(),
             save_button: "📥  Save".to_string(),
             cancel_button: "🚫 Cancel".to_string(),
 
-            overwrite_file_modal_text: "already exists. Do you want to overwrite it?".to_string(),
+            overwrite_file_modal_text: "'{name}' already exists. Do you want to overwrite it?"
+                .to_string(),
 
             err_empty_folder_name: "Name of the folder cannot be empty".to_string(),
             err_empty_file_name: "The file name cannot be empty".to_string(),
-            err_directory_exists: "A directory with the name already exists".to_string(),
-            err_file_exists: "A file with the name already exists".to_string(),
+            err_directory_exists: "A folder named '{name}' already exists".to_string(),
+            err_file_exists: "A file named '{name}' already exists".to_string(),
+            err_too_few_items: "Select at least {count|one item|{count} items}".to_string(),
+            err_too_many_items: "Select at most {count|one item|{count} items}".to_string(),
+            err_only_files: "Only files can be selected".to_string(),
+            err_only_directories: "Only folders can be selected".to_string(),
+        }
+    }
+}
+
+/// Implements the lookup of the labels by their name, used when loading translation files.
+macro_rules! label_names {
+    ($($field:ident),* $(,)?) => {
+        impl FileDialogLabels {
+            /// Names of all labels.
+            #[cfg(test)]
+            const NAMES: &[&str] = &[$(stringify!($field)),*];
+
+            /// Returns the label with the given name. Hyphens in the name are treated
+            /// as underscores, so that both `title-save-file` and `title_save_file` are valid.
+            fn label_mut(&mut self, name: &str) -> Option<&mut String> {
+                // Fails to compile if a field of the labels is missing in `label_names!`
+                let Self { $($field: _),* } = self;
+
+                match name.replace('-', "_").as_str() {
+                    $(stringify!($field) => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+label_names!(
+    locale,
+    title_select_directory,
+    title_select_file,
+    title_select_file_or_directory,
+    title_select_multiple,
+    title_save_file,
+    cancel,
+    overwrite,
+    reload,
+    working_directory,
+    show_hidden,
+    show_system_files,
+    show_quick_filter,
+    quick_filter_modified,
+    quick_filter_size,
+    quick_filter_any_time,
+    quick_filter_today,
+    quick_filter_last_seven_days,
+    quick_filter_custom,
+    quick_filter_min_size,
+    quick_filter_max_size,
+    quick_filter_modified_chip,
+    quick_filter_min_size_chip,
+    quick_filter_max_size_chip,
+    heading_pinned,
+    heading_places,
+    heading_bookmarks,
+    heading_recent,
+    heading_devices,
+    heading_removable_devices,
+    device_usage,
+    home_dir,
+    desktop_dir,
+    documents_dir,
+    downloads_dir,
+    audio_dir,
+    pictures_dir,
+    videos_dir,
+    templates_dir,
+    public_dir,
+    pin_folder,
+    unpin_folder,
+    rename_pinned_folder,
+    move_pinned_folder_to_group,
+    pinned_folder_no_group,
+    new_pinned_folder_group,
+    pinned_folder_appearance,
+    reset_pinned_folder_appearance,
+    remove_missing_pinned_folders,
+    pinned_folder_missing,
+    drop_to_pin,
+    selected_directory,
+    selected_file,
+    selected_item,
+    selected_items,
+    selection_summary,
+    selection_summary_without_folder_sizes,
+    selection_basket,
+    free_space,
+    file_name,
+    file_filter_all_files,
+    save_extension_any,
+    open_button,
+    select_button,
+    save_button,
+    cancel_button,
+    overwrite_file_modal_text,
+    err_empty_folder_name,
+    err_empty_file_name,
+    err_directory_exists,
+    err_file_exists,
+    err_too_few_items,
+    err_too_many_items,
+    err_only_files,
+    err_only_directories,
+);
+
+impl FileDialogLabels {
+    /// Returns the language codes of the bundled translations, including
+    /// the default english labels.
+    pub fn available_locales() -> Vec<&'static str> {
+        std::iter::once("en")
+            .chain(BUNDLED_LOCALES.iter().map(|(locale, _)| *locale))
+            .collect()
+    }
+
+    /// Creates the labels of the given locale, like `fr` or `de-AT`, using the bundled
+    /// translations. Only the language of the locale is considered.
+    ///
+    /// The default english labels are returned if there is no translation for the language.
+    /// Labels that are missing in a translation also fall back to english.
+    pub fn for_locale(locale: &str) -> Self {
+        let language = language_of(locale);
+
+        BUNDLED_LOCALES
+            .iter()
+            .find(|(locale, _)| *locale == language)
+            .map_or_else(Self::default, |(_, content)| Self::from_fluent(content))
+    }
+
+    /// Creates the labels from the content of a Fluent (`.ftl`) translation file.
+    ///
+    /// Messages are mapped to the labels by their ID, like `title-save-file`.
+    /// Variables like `{ $count }` become the placeholders of the label.
+    /// The language of the labels can be set using a `locale` message, like `locale = ar`.
+    /// Unknown messages are ignored and missing labels fall back to english.
+    pub fn from_fluent(content: &str) -> Self {
+        let mut labels = Self::default();
+
+        for (name, value) in fluent::parse(content) {
+            if let Some(label) = labels.label_mut(&name) {
+                *label = value;
+            }
+        }
+
+        labels
+    }
+
+    /// Creates the labels from the content of a TOML translation file, containing
+    /// the labels as string values, like `title_save_file = "📥 Save File"`.
+    ///
+    /// Unknown keys are ignored and missing labels fall back to english.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        let table: toml::Table = toml::from_str(content)?;
+        let mut labels = Self::default();
+
+        for (name, value) in table {
+            if let (Some(label), toml::Value::String(value)) = (labels.label_mut(&name), value) {
+                *label = value;
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Returns if the language of the labels is written from right to left,
+    /// like Arabic or Hebrew, based on `FileDialogLabels::locale`.
+    pub fn is_right_to_left(&self) -> bool {
+        RIGHT_TO_LEFT_LANGUAGES.contains(&language_of(&self.locale).as_str())
+    }
+
+    /// Replaces the placeholders of a label with the given values, like `format_label`,
+    /// but selects the plural forms using the plural rule of the language of the labels,
+    /// based on `FileDialogLabels::locale`.
+    ///
+    /// French, for example, uses the singular form for 0 as well, while English uses it
+    /// only for 1. Only these two rules are supported; languages with more plural forms,
+    /// like Arabic distinguishing up to six, use the plural form for values other than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialogLabels;
+    ///
+    /// let labels = FileDialogLabels::for_locale("fr");
+    /// let label = "{count|{count} élément|{count} éléments}";
+    ///
+    /// assert_eq!(labels.format(label, &[("count", "0")]), "0 élément");
+    /// assert_eq!(labels.format(label, &[("count", "2")]), "2 éléments");
+    /// ```
+    pub fn format(&self, label: &str, args: &[(&str, &str)]) -> String {
+        let language = language_of(&self.locale);
+
+        if ZERO_IS_SINGULAR_LANGUAGES.contains(&language.as_str()) {
+            format_with_plural_rule(label, args, |value| {
+                value.parse::<u64>().is_ok_and(|n| n <= 1)
+            })
+        } else {
+            format_label(label, args)
+        }
+    }
+
+    /// Returns the name of the given user directory, like "Documents".
+    pub fn user_directory(&self, directory: UserDirectory) -> &str {
+        match directory {
+            UserDirectory::Home => &self.home_dir,
+            UserDirectory::Desktop => &self.desktop_dir,
+            UserDirectory::Documents => &self.documents_dir,
+            UserDirectory::Downloads => &self.downloads_dir,
+            UserDirectory::Audio => &self.audio_dir,
+            UserDirectory::Pictures => &self.pictures_dir,
+            UserDirectory::Videos => &self.videos_dir,
+            UserDirectory::Templates => &self.templates_dir,
+            UserDirectory::Public => &self.public_dir,
+        }
+    }
+}
+
+/// Returns the lowercase language of a locale, like `de` for `de-AT`.
+fn language_of(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Replaces the placeholders of a label with the given values.
+///
+/// A placeholder like `{count}` is replaced with the value of the argument `count`.
+/// Plural forms are written as `{count|singular|plural}`: the singular form is used if
+/// the value is 1, the plural form otherwise. The forms can contain placeholders
+/// themselves, like `{count|One item|{count} items}`.
+///
+/// This is the plural rule of English. Use `FileDialogLabels::format` to select the
+/// forms using the plural rule of the language of the labels, like French using the
+/// singular form for 0 as well.
+///
+/// Placeholders without a matching argument are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use egui_file_dialog::format_label;
+///
+/// let label = "{count|One item|{count} items} selected";
+///
+/// assert_eq!(format_label(label, &[("count", "1")]), "One item selected");
+/// assert_eq!(format_label(label, &[("count", "3")]), "3 items selected");
+/// ```
+pub fn format_label(label: &str, args: &[(&str, &str)]) -> String {
+    format_with_plural_rule(label, args, |value| value == "1")
+}
+
+/// Replaces the placeholders of a label with the given values, see `format_label`.
+/// The singular form is used for the values the given plural rule returns true for.
+fn format_with_plural_rule(
+    label: &str,
+    args: &[(&str, &str)],
+    is_singular: fn(&str) -> bool,
+) -> String {
+    let mut result = String::with_capacity(label.len());
+    let mut rest = label;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = find_closing_brace(rest) else {
+            break;
+        };
+
+        let forms = split_forms(&rest[1..end]);
+        let value = forms
+            .first()
+            .and_then(|name| args.iter().find(|(n, _)| *n == name.trim()))
+            .map(|(_, value)| *value);
+
+        match (value, &forms[1..]) {
+            (Some(value), []) => result.push_str(value),
+            (Some(value), [singular, others @ ..]) => {
+                let plural = others.last().unwrap_or(singular);
+                let form = if is_singular(value) { singular } else { plural };
+                result.push_str(&format_with_plural_rule(form, args, is_singular));
+            }
+            (None, _) => result.push_str(&rest[..=end]),
         }
+
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Returns the index of the brace closing the brace at the start of the text,
+/// taking nested braces into account.
+pub(super) fn find_closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits the content of a placeholder at the `|` separators that are not nested
+/// inside another placeholder.
+fn split_forms(text: &str) -> Vec<&str> {
+    let mut forms = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                forms.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    forms.push(&text[start..]);
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_locales_are_complete() {
+        for (locale, content) in BUNDLED_LOCALES {
+            let mut labels = FileDialogLabels::default();
+            let messages = fluent::parse(content);
+
+            for (name, _) in &messages {
+                assert!(
+                    labels.label_mut(name).is_some(),
+                    "unknown label {name} in {locale}"
+                );
+            }
+
+            for name in FileDialogLabels::NAMES {
+                assert!(
+                    messages.iter().any(|(n, _)| n.replace('-', "_") == *name),
+                    "missing label {name} in {locale}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn selects_locale() {
+        let labels = FileDialogLabels::for_locale("fr-CA");
+
+        assert_eq!(labels.title_save_file, "📥 Enregistrer le fichier");
+        assert_eq!(labels.show_hidden, " Afficher les fichiers cachés");
+        assert_eq!(
+            labels.err_too_few_items,
+            "Sélectionnez au moins {count|un élément|{count} éléments}"
+        );
+        assert_eq!(
+            format_label(&labels.overwrite_file_modal_text, &[("name", "a.txt")]),
+            "'a.txt' existe déjà. Voulez-vous le remplacer ?"
+        );
+        assert_eq!(
+            FileDialogLabels::for_locale("xx"),
+            FileDialogLabels::default()
+        );
+    }
+
+    #[test]
+    fn detects_right_to_left() {
+        let labels = |locale: &str| FileDialogLabels {
+            locale: locale.to_string(),
+            ..Default::default()
+        };
+
+        assert!(labels("ar-EG").is_right_to_left());
+        assert!(labels("he").is_right_to_left());
+        assert!(!labels("en").is_right_to_left());
+        assert!(!FileDialogLabels::for_locale("de").is_right_to_left());
+    }
+
+    #[test]
+    fn formats_placeholders() {
+        let label = "{count|One item|{count} items} in '{name}', {unknown}";
+
+        assert_eq!(
+            format_label(label, &[("count", "1"), ("name", "a")]),
+            "One item in 'a', {unknown}"
+        );
+        assert_eq!(
+            format_label(label, &[("count", "0"), ("name", "b")]),
+            "0 items in 'b', {unknown}"
+        );
+        assert_eq!(format_label("{count|items}", &[("count", "1")]), "items");
+        assert_eq!(format_label("{count", &[("count", "1")]), "{count");
+    }
+
+    #[test]
+    fn selects_plural_forms_by_locale() {
+        let label = "{count|singular|plural}";
+        let format = |locale: &str, count: &str| {
+            FileDialogLabels {
+                locale: locale.to_string(),
+                ..Default::default()
+            }
+            .format(label, &[("count", count)])
+        };
+
+        assert_eq!(format("en", "0"), "plural");
+        assert_eq!(format("en", "1"), "singular");
+        assert_eq!(format("fr-CA", "0"), "singular");
+        assert_eq!(format("fr", "1"), "singular");
+        assert_eq!(format("fr", "2"), "plural");
+
+        let labels = FileDialogLabels::for_locale("fr");
+        assert_eq!(
+            labels.format(&labels.selected_items, &[("count", "0")]),
+            "0 élément sélectionné :"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn loads_toml() {
+        let labels = FileDialogLabels::from_toml("title_save_file = \"Speichern\"\nunknown = 1\n");
+
+        assert_eq!(
+            labels.ok(),
+            Some(FileDialogLabels {
+                title_save_file: "Speichern".to_string(),
+                ..Default::default()
+            })
+        );
     }
 }
@@ -0,0 +1,114 @@
+# German translation of the file dialog labels.
+
+locale = de
+
+title-select-directory = 📁 Ordner Öffnen
+title-select-file = 📂 Datei Öffnen
+title-select-file-or-directory = 🗁 Datei oder Ordner Auswählen
+title-select-multiple = 🗐 Mehrere Öffnen
+title-save-file = 📥 Datei Speichern
+
+cancel = Abbrechen
+overwrite = Überschreiben
+
+reload = ⟲  Neu laden
+working-directory = Arbeitsverzeichnis öffnen
+show-hidden = {" "}Versteckte Dateien anzeigen
+show-system-files = {" "}Systemdateien anzeigen
+show-quick-filter = {" "}Nach Datum und Größe filtern
+
+quick-filter-modified = Geändert:
+quick-filter-size = Größe:
+quick-filter-any-time = Beliebig
+quick-filter-today = Heute
+quick-filter-last-seven-days = Letzte 7 Tage
+quick-filter-custom = Benutzerdefiniert
+quick-filter-min-size = Min
+quick-filter-max-size = Max
+quick-filter-modified-chip = Geändert: { $range }
+quick-filter-min-size-chip = Größe ≥ { $size }
+quick-filter-max-size-chip = Größe ≤ { $size }
+
+heading-pinned = Angeheftet
+heading-places = Orte
+heading-bookmarks = Lesezeichen
+heading-recent = Zuletzt verwendet
+heading-devices = Medien
+heading-removable-devices = Wechselmedien
+device-usage = { $available } von { $total } frei
+
+home-dir = 🏠  Zuhause
+desktop-dir = 🖵  Desktop
+documents-dir = 🗐  Dokumente
+downloads-dir = 📥  Downloads
+audio-dir = 🎵  Audio
+pictures-dir = 🖼  Fotos
+videos-dir = 🎞  Videos
+templates-dir = 📄  Vorlagen
+public-dir = 👥  Öffentlich
+
+pin-folder = 📌 Ordner anheften
+unpin-folder = ✖ Ordner loslösen
+rename-pinned-folder = ✏ Ordner umbenennen
+move-pinned-folder-to-group = 🗀 In Gruppe verschieben
+pinned-folder-no-group = Keine Gruppe
+new-pinned-folder-group = Neue Gruppe
+pinned-folder-appearance = 🎨 Symbol und Farbe
+reset-pinned-folder-appearance = ⟲ Zurücksetzen
+remove-missing-pinned-folders = 🗑 Fehlende Ordner loslösen
+pinned-folder-missing = Der Ordner existiert nicht mehr
+drop-to-pin = Zum Anheften hier ablegen
+
+selected-directory = Ausgewählter Ordner:
+selected-file = Ausgewählte Datei:
+selected-item = Ausgewähltes Element:
+selected-items =
+    { $count ->
+        [one] Ein Element ausgewählt:
+       *[other] { $count } Elemente ausgewählt:
+    }
+selection-summary =
+    { $files ->
+        [one] 1 Datei
+       *[other] { $files } Dateien
+    }, { $folders ->
+        [one] 1 Ordner
+       *[other] { $folders } Ordner
+    }, insgesamt { $size }
+selection-summary-without-folder-sizes =
+    { $files ->
+        [one] 1 Datei
+       *[other] { $files } Dateien
+    } ({ $size }), { $folders ->
+        [one] 1 Ordner
+       *[other] { $folders } Ordner
+    }
+selection-basket = Auswahl ({ $count })
+free-space = { $size } frei
+file-name = Dateiname:
+file-filter-all-files = Alle Dateien
+save-extension-any = Alle
+
+open-button = 🗀  Öffnen
+select-button = ✔  Auswählen
+save-button = 📥  Speichern
+cancel-button = 🚫 Abbrechen
+
+overwrite-file-modal-text = '{ $name }' existiert bereits. Möchtest du es überschreiben?
+
+err-empty-folder-name = Der Ordnername darf nicht leer sein
+err-empty-file-name = Der Dateiname darf nicht leer sein
+err-directory-exists = Ein Ordner namens '{ $name }' existiert bereits
+err-file-exists = Eine Datei namens '{ $name }' existiert bereits
+err-too-few-items =
+    Wähle mindestens { $count ->
+        [one] ein Element
+       *[other] { $count } Elemente
+    } aus
+err-too-many-items =
+    Wähle höchstens { $count ->
+        [one] ein Element
+       *[other] { $count } Elemente
+    } aus
+err-only-files = Es können nur Dateien ausgewählt werden
+err-only-directories = Es können nur Ordner ausgewählt werden
@@ -0,0 +1,114 @@
+# Spanish translation of the file dialog labels.
+
+locale = es
+
+title-select-directory = 📁 Seleccionar carpeta
+title-select-file = 📂 Abrir archivo
+title-select-file-or-directory = 🗁 Seleccionar archivo o carpeta
+title-select-multiple = 🗐 Selección múltiple
+title-save-file = 📥 Guardar archivo
+
+cancel = Cancelar
+overwrite = Sobrescribir
+
+reload = ⟲  Recargar
+working-directory = ↗  Ir al directorio de trabajo
+show-hidden = {" "}Mostrar archivos ocultos
+show-system-files = {" "}Mostrar archivos del sistema
+show-quick-filter = {" "}Filtrar por fecha y tamaño
+
+quick-filter-modified = Modificado:
+quick-filter-size = Tamaño:
+quick-filter-any-time = Cualquier fecha
+quick-filter-today = Hoy
+quick-filter-last-seven-days = Últimos 7 días
+quick-filter-custom = Personalizado
+quick-filter-min-size = Mín
+quick-filter-max-size = Máx
+quick-filter-modified-chip = Modificado: { $range }
+quick-filter-min-size-chip = Tamaño ≥ { $size }
+quick-filter-max-size-chip = Tamaño ≤ { $size }
+
+heading-pinned = Fijados
+heading-places = Lugares
+heading-bookmarks = Marcadores
+heading-recent = Recientes
+heading-devices = Dispositivos
+heading-removable-devices = Dispositivos extraíbles
+device-usage = { $available } libres de { $total }
+
+home-dir = 🏠  Carpeta personal
+desktop-dir = 🖵  Escritorio
+documents-dir = 🗐  Documentos
+downloads-dir = 📥  Descargas
+audio-dir = 🎵  Música
+pictures-dir = 🖼  Imágenes
+videos-dir = 🎞  Vídeos
+templates-dir = 📄  Plantillas
+public-dir = 👥  Público
+
+pin-folder = 📌 Fijar
+unpin-folder = ✖ Desfijar
+rename-pinned-folder = ✏ Renombrar
+move-pinned-folder-to-group = 🗀 Mover al grupo
+pinned-folder-no-group = Sin grupo
+new-pinned-folder-group = Nuevo grupo
+pinned-folder-appearance = 🎨 Icono y color
+reset-pinned-folder-appearance = ⟲ Restablecer
+remove-missing-pinned-folders = 🗑 Quitar carpetas inexistentes
+pinned-folder-missing = La carpeta ya no existe
+drop-to-pin = Suelta aquí para fijar
+
+selected-directory = Carpeta seleccionada:
+selected-file = Archivo seleccionado:
+selected-item = Elemento seleccionado:
+selected-items =
+    { $count ->
+        [one] Un elemento seleccionado:
+       *[other] { $count } elementos seleccionados:
+    }
+selection-summary =
+    { $files ->
+        [one] 1 archivo
+       *[other] { $files } archivos
+    }, { $folders ->
+        [one] 1 carpeta
+       *[other] { $folders } carpetas
+    }, { $size } en total
+selection-summary-without-folder-sizes =
+    { $files ->
+        [one] 1 archivo
+       *[other] { $files } archivos
+    } ({ $size }), { $folders ->
+        [one] 1 carpeta
+       *[other] { $folders } carpetas
+    }
+selection-basket = Selección ({ $count })
+free-space = { $size } libres
+file-name = Nombre del archivo:
+file-filter-all-files = Todos los archivos
+save-extension-any = Cualquiera
+
+open-button = 🗀  Abrir
+select-button = ✔  Seleccionar
+save-button = 📥  Guardar
+cancel-button = 🚫 Cancelar
+
+overwrite-file-modal-text = '{ $name }' ya existe. ¿Quieres sobrescribirlo?
+
+err-empty-folder-name = El nombre de la carpeta no puede estar vacío
+err-empty-file-name = El nombre del archivo no puede estar vacío
+err-directory-exists = Ya existe una carpeta llamada '{ $name }'
+err-file-exists = Ya existe un archivo llamado '{ $name }'
+err-too-few-items =
+    Selecciona al menos { $count ->
+        [one] un elemento
+       *[other] { $count } elementos
+    }
+err-too-many-items =
+    Selecciona como máximo { $count ->
+        [one] un elemento
+       *[other] { $count } elementos
+    }
+err-only-files = Solo se pueden seleccionar archivos
+err-only-directories = Solo se pueden seleccionar carpetas
@@ -0,0 +1,114 @@
+# French translation of the file dialog labels.
+
+locale = fr
+
+title-select-directory = 📁 Sélectionner un dossier
+title-select-file = 📂 Ouvrir un fichier
+title-select-file-or-directory = 🗁 Sélectionner un fichier ou un dossier
+title-select-multiple = 🗐 Sélection multiple
+title-save-file = 📥 Enregistrer le fichier
+
+cancel = Annuler
+overwrite = Remplacer
+
+reload = ⟲  Actualiser
+working-directory = ↗  Aller au répertoire de travail
+show-hidden = {" "}Afficher les fichiers cachés
+show-system-files = {" "}Afficher les fichiers système
+show-quick-filter = {" "}Filtrer par date et taille
+
+quick-filter-modified = Modifié :
+quick-filter-size = Taille :
+quick-filter-any-time = N'importe quand
+quick-filter-today = Aujourd'hui
+quick-filter-last-seven-days = 7 derniers jours
+quick-filter-custom = Personnalisé
+quick-filter-min-size = Min
+quick-filter-max-size = Max
+quick-filter-modified-chip = Modifié : { $range }
+quick-filter-min-size-chip = Taille ≥ { $size }
+quick-filter-max-size-chip = Taille ≤ { $size }
+
+heading-pinned = Épinglés
+heading-places = Emplacements
+heading-bookmarks = Signets
+heading-recent = Récents
+heading-devices = Périphériques
+heading-removable-devices = Périphériques amovibles
+device-usage = { $available } libres sur { $total }
+
+home-dir = 🏠  Dossier personnel
+desktop-dir = 🖵  Bureau
+documents-dir = 🗐  Documents
+downloads-dir = 📥  Téléchargements
+audio-dir = 🎵  Musique
+pictures-dir = 🖼  Images
+videos-dir = 🎞  Vidéos
+templates-dir = 📄  Modèles
+public-dir = 👥  Public
+
+pin-folder = 📌 Épingler
+unpin-folder = ✖ Détacher
+rename-pinned-folder = ✏ Renommer
+move-pinned-folder-to-group = 🗀 Déplacer vers le groupe
+pinned-folder-no-group = Aucun groupe
+new-pinned-folder-group = Nouveau groupe
+pinned-folder-appearance = 🎨 Icône et couleur
+reset-pinned-folder-appearance = ⟲ Réinitialiser
+remove-missing-pinned-folders = 🗑 Retirer les dossiers introuvables
+pinned-folder-missing = Le dossier n'existe plus
+drop-to-pin = Déposer ici pour épingler
+
+selected-directory = Dossier sélectionné :
+selected-file = Fichier sélectionné :
+selected-item = Élément sélectionné :
+selected-items =
+    { $count ->
+        [one] { $count } élément sélectionné :
+       *[other] { $count } éléments sélectionnés :
+    }
+selection-summary =
+    { $files ->
+        [one] { $files } fichier
+       *[other] { $files } fichiers
+    }, { $folders ->
+        [one] { $folders } dossier
+       *[other] { $folders } dossiers
+    }, { $size } au total
+selection-summary-without-folder-sizes =
+    { $files ->
+        [one] { $files } fichier
+       *[other] { $files } fichiers
+    } ({ $size }), { $folders ->
+        [one] { $folders } dossier
+       *[other] { $folders } dossiers
+    }
+selection-basket = Sélection ({ $count })
+free-space = { $size } libres
+file-name = Nom du fichier :
+file-filter-all-files = Tous les fichiers
+save-extension-any = Toutes
+
+open-button = 🗀  Ouvrir
+select-button = ✔  Sélectionner
+save-button = 📥  Enregistrer
+cancel-button = 🚫 Annuler
+
+overwrite-file-modal-text = '{ $name }' existe déjà. Voulez-vous le remplacer ?
+
+err-empty-folder-name = Le nom du dossier ne peut pas être vide
+err-empty-file-name = Le nom du fichier ne peut pas être vide
+err-directory-exists = Un dossier nommé « { $name } » existe déjà
+err-file-exists = Un fichier nommé « { $name } » existe déjà
+err-too-few-items =
+    Sélectionnez au moins { $count ->
+        [one] un élément
+       *[other] { $count } éléments
+    }
+err-too-many-items =
+    Sélectionnez au plus { $count ->
+        [one] { $count } élément
+       *[other] { $count } éléments
+    }
+err-only-files = Seuls des fichiers peuvent être sélectionnés
+err-only-directories = Seuls des dossiers peuvent être sélectionnés
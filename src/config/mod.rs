@@ -1,23 +1,50 @@
+mod fluent;
 mod labels;
-pub use labels::FileDialogLabels;
+pub use labels::{format_label, FileDialogLabels};
 
 mod keybindings;
 pub use keybindings::{FileDialogKeyBindings, KeyBinding};
 
+mod file_filter_spec;
+pub use file_filter_spec::FileFilterSpec;
+
+mod selection_constraints;
+pub use selection_constraints::{SelectableEntries, SelectionConstraints};
+
+mod system_bookmarks;
+pub use system_bookmarks::load_system_bookmarks;
+
+#[cfg(feature = "recently_used")]
+mod recently_used;
+#[cfg(feature = "recently_used")]
+pub use recently_used::{add_recent_files, load_recent_files};
+
+mod xbel;
+
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::{FileSystem, NativeFileSystem};
+use crate::{FileSystem, MimeDatabase, NativeFileSystem, UserDirectory};
 
 /// Folder that the user pinned to the left sidebar.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PinnedFolder {
     /// Path to the folder.
     pub path: PathBuf,
     /// Display name of the folder shown in the left panel.
     pub label: String,
+    /// Name of the group the folder is listed in.
+    /// Folders without a group are listed before the groups.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub group: Option<String>,
+    /// Icon of the folder. If None, `FileDialogConfig::pinned_icon` is used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub icon: Option<String>,
+    /// Color of the folder's label. If None, the default text color is used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color: Option<egui::Color32>,
 }
 
 impl PinnedFolder {
@@ -30,7 +57,54 @@ impl PinnedFolder {
             .to_string_lossy()
             .into_owned();
 
-        Self { path, label }
+        Self {
+            path,
+            label,
+            group: None,
+            icon: None,
+            color: None,
+        }
+    }
+
+    /// Sets the name of the group the folder is listed in.
+    #[must_use]
+    pub fn with_group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Sets the icon of the folder.
+    #[must_use]
+    pub fn with_icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    /// Sets the color of the folder's label.
+    #[must_use]
+    pub fn with_color(mut self, color: egui::Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A user directory listed in the Places section of the left sidebar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Place {
+    /// The user directory that is listed.
+    pub directory: UserDirectory,
+    /// Display name of the place shown in the left panel.
+    /// If None, the name is taken from the `FileDialogLabels`.
+    pub display_name: Option<String>,
+}
+
+impl Place {
+    /// Creates a new place of the given user directory using the default display name.
+    pub const fn new(directory: UserDirectory) -> Self {
+        Self {
+            directory,
+            display_name: None,
+        }
     }
 }
 
@@ -46,6 +120,20 @@ pub enum OpeningMode {
     LastPickedDir,
 }
 
+/// Sets how the MIME type of files is detected.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MimeDetection {
+    /// MIME types are not detected.
+    Disabled,
+    /// The MIME type is detected using only the file extension.
+    Extension,
+    /// The MIME type is detected using the file extension. If the extension is unknown,
+    /// the beginning of the file is read to detect the type by its content.
+    /// This requires reading every file of a directory with unknown extension and
+    /// can therefore be slow on large directories or slow hard drives.
+    ExtensionAndContent,
+}
+
 /// Contains configuration values of a file dialog.
 ///
 /// The configuration of a file dialog can be set using `FileDialog::with_config`.
@@ -95,6 +183,13 @@ pub struct FileDialogConfig {
     pub as_modal: bool,
     /// Color of the overlay that is displayed under the modal to prevent user interaction.
     pub modal_overlay_color: egui::Color32,
+    /// If the dialog should be laid out from right to left, for languages like Arabic or Hebrew.
+    /// This mirrors the side panels, the navigation buttons, the path display and the
+    /// bottom panel.
+    ///
+    /// If None, the direction is derived from the locale of the labels.
+    /// See `FileDialogLabels::is_right_to_left`.
+    pub right_to_left: Option<bool>,
     /// The first directory that will be opened when the dialog opens.
     pub initial_directory: PathBuf,
     /// The default filename when opening the dialog in `DialogMode::SaveFile` mode.
@@ -122,6 +217,30 @@ pub struct FileDialogConfig {
     pub load_via_thread: bool,
     /// If we should truncate the filenames in the middle
     pub truncate_filenames: bool,
+    /// If typing while no input is focused should jump the selection to the first item
+    /// whose name starts with the typed text, instead of typing into the search input.
+    pub type_ahead_selection: bool,
+    /// Time after the last key press after which the typed text of the type-ahead selection
+    /// is discarded and a new prefix is started.
+    pub type_ahead_timeout: std::time::Duration,
+    /// Constraints of the selection when the dialog is in `DialogMode::PickMultiple` mode.
+    pub selection_constraints: SelectionConstraints,
+    /// If the size of directories should be calculated recursively in the background and
+    /// displayed in the central panel and the information panel.
+    pub calculate_directory_sizes: bool,
+    /// Interval in which the system disks are scanned while the dialog is open, to detect
+    /// devices that were added or removed, like USB drives.
+    /// None if the disks should only be loaded when the dialog is opened or reloaded.
+    ///
+    /// While the dialog is open, a repaint is requested at the end of every interval,
+    /// so the application is repainted at least that often. Defaults to None.
+    pub disk_rescan_interval: Option<std::time::Duration>,
+    /// Maximum depth of subdirectories that are included when calculating the size of
+    /// a directory. Also prevents endless loops caused by symbolic links.
+    pub directory_size_max_depth: usize,
+    /// Maximum time spent calculating the size of a single directory.
+    /// If exceeded, the size counted so far is displayed as a lower bound.
+    pub directory_size_time_budget: std::time::Duration,
 
     /// The icon that is used to display error messages.
     pub err_icon: String,
@@ -149,6 +268,15 @@ pub struct FileDialogConfig {
     /// Sets custom icons for different files or folders.
     /// Use `FileDialogConfig::set_file_icon` to add a new icon to this list.
     pub file_icon_filters: Vec<IconFilter>,
+    /// Sets custom icons for files with specific MIME types.
+    /// Use `FileDialogConfig::set_file_icon_mime` to add a new icon to this list.
+    /// Icons set using `file_icon_filters` take precedence.
+    pub mime_icon_filters: Vec<MimeIconFilter>,
+
+    /// Sets how the MIME type of files is detected.
+    pub mime_detection: MimeDetection,
+    /// Database used to detect the MIME type of files and to get their description.
+    pub mime_database: Arc<MimeDatabase>,
 
     /// Custom sections added to the left sidebar for quick access.
     /// Use `FileDialogConfig::add_quick_access` to add a new section to this list.
@@ -207,6 +335,9 @@ pub struct FileDialogConfig {
     pub show_hidden_option: bool,
     /// If the show system files option inside the top panel menu should be visible.
     pub show_system_files_option: bool,
+    /// If the option to show the quick filter bar, used to filter files by modification
+    /// date and size, inside the top panel menu should be visible.
+    pub show_quick_filter_option: bool,
     /// If the search input in the top panel should be visible.
     pub show_search: bool,
 
@@ -221,6 +352,21 @@ pub struct FileDialogConfig {
     pub show_pinned_folders: bool,
     /// If the Places section in the left sidebar should be visible.
     pub show_places: bool,
+    /// The user directories listed in the Places section, in their default order.
+    /// The user can reorder the places by dragging them, which is stored in
+    /// `FileDialogStorage::place_order`.
+    pub places: Vec<Place>,
+    /// If the bookmarks the user created in the file manager of the desktop environment
+    /// (GTK bookmarks and KDE places) should be listed in a section in the left sidebar.
+    pub show_system_bookmarks: bool,
+    /// If the files recently used by any application, read from the freedesktop
+    /// `recently-used.xbel` list, should be listed in a section in the left sidebar.
+    #[cfg(feature = "recently_used")]
+    pub show_recent_files: bool,
+    /// Name of the application under which picked files are added to the freedesktop
+    /// `recently-used.xbel` list. None if picked files should not be added to the list.
+    #[cfg(feature = "recently_used")]
+    pub recent_files_application: Option<String>,
     /// If the Devices section in the left sidebar should be visible.
     pub show_devices: bool,
     /// If the Removable Devices section in the left sidebar should be visible.
@@ -243,6 +389,7 @@ impl FileDialogConfig {
             opening_mode: OpeningMode::LastPickedDir,
             as_modal: true,
             modal_overlay_color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 120),
+            right_to_left: None,
             initial_directory: file_system.current_dir().unwrap_or_default(),
             default_file_name: String::from("Untitled"),
             allow_file_overwrite: true,
@@ -256,6 +403,13 @@ impl FileDialogConfig {
             load_via_thread: true,
 
             truncate_filenames: true,
+            type_ahead_selection: false,
+            type_ahead_timeout: std::time::Duration::from_secs(1),
+            selection_constraints: SelectionConstraints::default(),
+            calculate_directory_sizes: false,
+            disk_rescan_interval: None,
+            directory_size_max_depth: 32,
+            directory_size_time_budget: std::time::Duration::from_secs(10),
 
             err_icon: String::from("⚠"),
             warn_icon: String::from("⚠"),
@@ -270,6 +424,10 @@ impl FileDialogConfig {
             save_extensions: Vec::new(),
             default_save_extension: None,
             file_icon_filters: Vec::new(),
+            mime_icon_filters: Vec::new(),
+
+            mime_detection: MimeDetection::Extension,
+            mime_database: Arc::new(MimeDatabase::new()),
 
             quick_accesses: Vec::new(),
 
@@ -297,12 +455,19 @@ impl FileDialogConfig {
             show_working_directory_button: true,
             show_hidden_option: true,
             show_system_files_option: true,
+            show_quick_filter_option: true,
             show_search: true,
 
             right_panel_width: None,
             show_left_panel: true,
             show_pinned_folders: true,
             show_places: true,
+            places: UserDirectory::DEFAULT_PLACES.map(Place::new).to_vec(),
+            show_system_bookmarks: false,
+            #[cfg(feature = "recently_used")]
+            show_recent_files: false,
+            #[cfg(feature = "recently_used")]
+            recent_files_application: None,
             show_devices: true,
             show_removable_devices: true,
 
@@ -337,22 +502,32 @@ impl FileDialogConfig {
     ///         "JPG files",
     ///         Arc::new(|path| path.extension().unwrap_or_default() == "jpg"));
     /// ```
-    pub fn add_file_filter(mut self, name: &str, filter: Filter<Path>) -> Self {
-        let id = egui::Id::new(name);
-
-        // Replace filter if a filter with the same name already exists.
-        if let Some(item) = self.file_filters.iter_mut().find(|p| p.id == id) {
-            item.filter = filter.clone();
-            return self;
-        }
-
-        self.file_filters.push(FileFilter {
-            id,
-            name: name.to_owned(),
-            filter,
-        });
+    pub fn add_file_filter(self, name: &str, filter: Filter<Path>) -> Self {
+        self.insert_file_filter(name, filter, None)
+    }
 
-        self
+    /// Adds a new file filter from a declarative specification.
+    ///
+    /// The specification is matched against the file system and MIME detection options
+    /// of the configuration when the filter is applied, so options set after adding the
+    /// filter are used as well. The patterns of the specification are displayed next to
+    /// the name of the filter, for example `Images (*.png, *.jpg)`.
+    ///
+    /// NOTE: The name must be unique. If a filter with the same name already exists,
+    ///       it will be overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::{FileDialogConfig, FileFilterSpec};
+    ///
+    /// FileDialogConfig::default()
+    ///     .add_file_filter_spec("Images", FileFilterSpec::extensions(&["png", "jpg"]))
+    ///     .add_file_filter_spec("Archives", FileFilterSpec::globs(&["*.zip", "*.tar.gz"]));
+    /// ```
+    pub fn add_file_filter_spec(self, name: &str, spec: FileFilterSpec) -> Self {
+        let filter = spec.compile(&self);
+        self.insert_file_filter(name, filter, Some(spec))
     }
 
     /// Shortctut method to add a file filter that matches specific extensions.
@@ -384,6 +559,30 @@ impl FileDialogConfig {
         )
     }
 
+    /// Shortcut method to add a file filter that matches specific MIME types.
+    ///
+    /// The MIME type of a file is detected using the `mime_database` and `mime_detection`
+    /// of the configuration when the filter is applied. If MIME detection is disabled,
+    /// the file extension is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Display name of the filter
+    /// * `mime_types` - The MIME types to match. Wildcards like `image/*` are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialogConfig;
+    ///
+    /// FileDialogConfig::default()
+    ///     .add_file_filter_mime("Images", &["image/*"])
+    ///     .add_file_filter_mime("Documents", &["application/pdf", "text/plain"]);
+    /// ```
+    pub fn add_file_filter_mime(self, name: &str, mime_types: &[&str]) -> Self {
+        self.add_file_filter_spec(name, FileFilterSpec::mime_types(mime_types))
+    }
+
     /// Adds a new file extension that the user can select in a dropdown widget when
     /// saving a file.
     ///
@@ -452,6 +651,34 @@ impl FileDialogConfig {
         self
     }
 
+    /// Sets a new icon for files with specific MIME types.
+    ///
+    /// Icons set using `FileDialogConfig::set_file_icon` take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - The icon that should be used.
+    /// * `mime_types` - The MIME types for which the icon should be used.
+    ///   Wildcards like `image/*` are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialogConfig;
+    ///
+    /// let config = FileDialogConfig::default()
+    ///     .set_file_icon_mime("🖻", &["image/*"])
+    ///     .set_file_icon_mime("🎵", &["audio/*"]);
+    /// ```
+    pub fn set_file_icon_mime(mut self, icon: &str, mime_types: &[&str]) -> Self {
+        self.mime_icon_filters.push(MimeIconFilter {
+            icon: icon.to_string(),
+            mime_types: mime_types.iter().map(|m| (*m).to_string()).collect(),
+        });
+
+        self
+    }
+
     /// Adds a new custom quick access section to the left panel of the file dialog.
     ///
     /// # Examples
@@ -482,6 +709,34 @@ impl FileDialogConfig {
     }
 }
 
+impl FileDialogConfig {
+    /// Adds the file filter or replaces the filter with the same name.
+    fn insert_file_filter(
+        mut self,
+        name: &str,
+        filter: Filter<Path>,
+        spec: Option<FileFilterSpec>,
+    ) -> Self {
+        let id = egui::Id::new(name);
+
+        // Replace filter if a filter with the same name already exists.
+        if let Some(item) = self.file_filters.iter_mut().find(|p| p.id == id) {
+            item.filter = filter;
+            item.spec = spec;
+            return self;
+        }
+
+        self.file_filters.push(FileFilter {
+            id,
+            name: name.to_owned(),
+            filter,
+            spec,
+        });
+
+        self
+    }
+}
+
 /// Function that returns true if the specific item matches the filter.
 pub type Filter<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
 
@@ -494,16 +749,49 @@ pub struct FileFilter {
     pub name: String,
     /// Sets a filter function that checks whether a given Path matches the criteria for this file.
     pub filter: Filter<Path>,
+    /// The declarative specification the filter function was compiled from, if any.
+    pub spec: Option<FileFilterSpec>,
+}
+
+impl FileFilter {
+    /// Checks if the given path matches the filter.
+    ///
+    /// Filters created from a `FileFilterSpec` are evaluated using the file system,
+    /// MIME database and MIME detection mode of the given configuration, so that changes
+    /// made after the filter was added are taken into account.
+    pub fn matches(&self, path: &Path, config: &FileDialogConfig) -> bool {
+        self.spec.as_ref().map_or_else(
+            || (self.filter)(path),
+            |spec| spec.matches_config(path, config),
+        )
+    }
 }
 
 impl std::fmt::Debug for FileFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FileFilter")
             .field("name", &self.name)
+            .field("spec", &self.spec)
             .finish()
     }
 }
 
+impl Display for FileFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let patterns = self
+            .spec
+            .as_ref()
+            .map(FileFilterSpec::patterns)
+            .unwrap_or_default();
+
+        if patterns.is_empty() {
+            f.write_str(&self.name)
+        } else {
+            f.write_str(&format!("{} ({})", &self.name, patterns.join(", ")))
+        }
+    }
+}
+
 /// Defines a specific file extension that the user can select when saving a file.
 #[derive(Clone, Debug)]
 pub struct SaveExtension {
@@ -538,6 +826,15 @@ impl std::fmt::Debug for IconFilter {
     }
 }
 
+/// Sets a specific icon for files with specific MIME types.
+#[derive(Debug, Clone)]
+pub struct MimeIconFilter {
+    /// The icon that should be used.
+    pub icon: String,
+    /// The MIME types for which the icon should be used. Wildcards like `image/*` are supported.
+    pub mime_types: Vec<String>,
+}
+
 /// Stores the display name and the actual path of a quick access link.
 #[derive(Debug, Clone)]
 pub struct QuickAccessPath {
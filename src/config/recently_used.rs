@@ -0,0 +1,334 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use super::{xbel, QuickAccess, QuickAccessPath};
+use crate::data::date;
+use crate::FileSystem;
+
+/// Location of the recently used files, relative to the home directory.
+const RECENTLY_USED: &str = ".local/share/recently-used.xbel";
+
+/// Maximum number of recently used files that are loaded.
+const MAX_RECENT_FILES: usize = 50;
+
+/// Content of a new recently used files list.
+const EMPTY_RECENTLY_USED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xbel version="1.0"
+      xmlns:bookmark="http://www.freedesktop.org/standards/desktop-bookmarks"
+      xmlns:mime="http://www.freedesktop.org/standards/shared-mime-info"
+>
+</xbel>
+"#;
+
+/// Loads the files that were recently used by any application from the freedesktop
+/// `recently-used.xbel` list in the given home directory.
+/// The most recently used files come first. Files that no longer exist are skipped.
+///
+/// Returns None if no recently used files were found.
+pub fn load_recent_files(
+    heading: &str,
+    file_system: &dyn FileSystem,
+    home_dir: &Path,
+    canonicalize_paths: bool,
+) -> Option<QuickAccess> {
+    let content = file_system.read_file(&home_dir.join(RECENTLY_USED)).ok()?;
+    let files = parse_recent_files(&String::from_utf8_lossy(&content));
+
+    let mut recent = QuickAccess {
+        canonicalize_paths,
+        heading: heading.to_string(),
+        paths: Vec::new(),
+    };
+
+    for file in files
+        .into_iter()
+        .filter(|file| file_system.is_file(&file.path))
+        .take(MAX_RECENT_FILES)
+    {
+        recent.add_path(&file.display_name, file.path);
+    }
+
+    if recent.paths.is_empty() {
+        return None;
+    }
+
+    Some(recent)
+}
+
+/// Parses the files of a `recently-used.xbel` list, most recently used first.
+fn parse_recent_files(content: &str) -> Vec<QuickAccessPath> {
+    let mut files: Vec<(String, QuickAccessPath)> = xbel::bookmarks(content)
+        .filter_map(|bookmark| {
+            let path = xbel::file_uri_to_path(&xbel::attribute(bookmark, "href")?)?;
+            let modified = xbel::attribute(bookmark, "modified").unwrap_or_default();
+
+            Some((
+                modified,
+                QuickAccessPath {
+                    display_name: xbel::path_display_name(&path),
+                    path,
+                },
+            ))
+        })
+        .collect();
+
+    // The timestamps are in ISO 8601 format and can be compared as strings.
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+
+    files.into_iter().map(|(_, file)| file).collect()
+}
+
+/// Adds the given files to the freedesktop `recently-used.xbel` list in the given
+/// home directory, registered as used by the given application.
+/// Each file is given with its MIME type. If a file is already contained, its
+/// modification time and the usage count of the application are updated.
+///
+/// The list is read and written once for all files.
+pub fn add_recent_files(
+    file_system: &dyn FileSystem,
+    home_dir: &Path,
+    files: &[(&Path, &str)],
+    application: &str,
+) -> std::io::Result<()> {
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let file = home_dir.join(RECENTLY_USED);
+
+    let mut content = file_system.read_file(&file).map_or_else(
+        |_| EMPTY_RECENTLY_USED.to_string(),
+        |content| String::from_utf8_lossy(&content).into_owned(),
+    );
+
+    let now = date::format_rfc3339(SystemTime::now(), 6);
+
+    for (path, mime_type) in files {
+        content = add_bookmark(
+            &content,
+            &xbel::path_to_file_uri(path),
+            application,
+            mime_type,
+            &now,
+        )
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "recently-used.xbel is not a valid XBEL file",
+            )
+        })?;
+    }
+
+    file_system.write_file(&file, content.as_bytes())
+}
+
+/// Adds or updates the bookmark of the given URI in the XBEL content.
+///
+/// Returns None if the content is not a valid XBEL file.
+fn add_bookmark(
+    content: &str,
+    uri: &str,
+    application: &str,
+    mime_type: &str,
+    now: &str,
+) -> Option<String> {
+    let mut content = content.to_string();
+    let mut added = now.to_string();
+    let mut count: u32 = 0;
+    let mut other_applications = Vec::new();
+
+    let pattern = format!("<bookmark href=\"{}\"", xbel::escape(uri));
+
+    if let Some(start) = content.find(&pattern) {
+        let existing = xbel::bookmarks(&content[start..]).next()?.to_string();
+
+        added = xbel::attribute(&existing, "added").unwrap_or(added);
+
+        // Applications are written as empty elements, either self-closing or followed
+        // by a closing tag, which is dropped as the elements are written again below.
+        for app in existing.split("<bookmark:application ").skip(1) {
+            let app = app[..app.find('>')?].trim_end_matches('/');
+
+            if xbel::attribute(&format!(" {app}>"), "name").as_deref() == Some(application) {
+                count = xbel::attribute(&format!(" {app}>"), "count")
+                    .and_then(|c| c.parse().ok())
+                    .unwrap_or_default();
+            } else {
+                other_applications.push(format!("<bookmark:application {}/>", app.trim()));
+            }
+        }
+
+        // Remove the existing bookmark including the indentation and line break
+        let line_start = content[..start].rfind('\n').map_or(start, |i| i + 1);
+        let mut end = start + existing.len();
+        if content[end..].starts_with('\n') {
+            end += 1;
+        }
+
+        content.replace_range(line_start..end, "");
+    }
+
+    let application = xbel::escape(application);
+    other_applications.push(format!(
+        "<bookmark:application name=\"{application}\" \
+         exec=\"&apos;{application} %u&apos;\" modified=\"{now}\" count=\"{}\"/>",
+        count + 1
+    ));
+
+    let applications = other_applications
+        .iter()
+        .fold(String::new(), |applications, app| {
+            applications + "          " + app + "\n"
+        });
+
+    let bookmark = format!(
+        "  <bookmark href=\"{}\" added=\"{added}\" modified=\"{now}\" visited=\"{now}\">\n\
+         \x20   <info>\n\
+         \x20     <metadata owner=\"http://freedesktop.org\">\n\
+         \x20       <mime:mime-type type=\"{}\"/>\n\
+         \x20       <bookmark:applications>\n\
+         {applications}\
+         \x20       </bookmark:applications>\n\
+         \x20     </metadata>\n\
+         \x20   </info>\n\
+         \x20 </bookmark>\n",
+        xbel::escape(uri),
+        xbel::escape(mime_type),
+    );
+
+    let end = content.rfind("</xbel>")?;
+    content.insert_str(end, &bookmark);
+
+    Some(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+    use crate::NativeFileSystem;
+
+    const NOW: &str = "2025-01-01T12:00:00.000000Z";
+
+    fn fixture() -> String {
+        std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/recently_used/home")
+                .join(RECENTLY_USED),
+        )
+        .unwrap_or_default()
+    }
+
+    #[test]
+    fn parses_files_most_recent_first() {
+        let names: Vec<String> = parse_recent_files(&fixture())
+            .into_iter()
+            .map(|file| file.display_name)
+            .collect();
+
+        assert_eq!(names, vec!["report final.pdf", "notes.txt", "photo.png"]);
+    }
+
+    #[test]
+    fn skips_missing_files() {
+        let home = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/recently_used/home");
+
+        // None of the files in the fixture exist
+        assert!(load_recent_files("Recent", &NativeFileSystem, &home, false).is_none());
+    }
+
+    #[test]
+    fn adds_new_bookmark() {
+        let content = add_bookmark(
+            EMPTY_RECENTLY_USED,
+            "file:///tmp/a%20b.txt",
+            "My App",
+            "text/plain",
+            NOW,
+        )
+        .unwrap_or_default();
+
+        let bookmarks: Vec<&str> = xbel::bookmarks(&content).collect();
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(
+            xbel::attribute(bookmarks[0], "href").as_deref(),
+            Some("file:///tmp/a%20b.txt")
+        );
+        assert!(bookmarks[0].contains("name=\"My App\""));
+        assert!(bookmarks[0].contains("count=\"1\""));
+        assert!(content.trim_end().ends_with("</xbel>"));
+    }
+
+    #[test]
+    fn updates_existing_bookmark() {
+        let content = fixture();
+        let uri = "file:///does/not/exist/notes.txt";
+
+        let updated = add_bookmark(&content, uri, "gedit", "text/plain", NOW).unwrap_or_default();
+
+        let bookmarks: Vec<&str> = xbel::bookmarks(&updated).collect();
+        assert_eq!(bookmarks.len(), xbel::bookmarks(&content).count());
+
+        let bookmark = bookmarks
+            .iter()
+            .find(|b| xbel::attribute(b, "href").as_deref() == Some(uri))
+            .copied()
+            .unwrap_or_default();
+
+        assert_eq!(
+            xbel::attribute(bookmark, "added").as_deref(),
+            Some("2024-03-01T09:00:00.000000Z")
+        );
+        assert_eq!(xbel::attribute(bookmark, "modified").as_deref(), Some(NOW));
+        assert!(bookmark.contains("name=\"org.gnome.TextEditor\""));
+        assert!(bookmark.contains("name=\"gedit\""));
+        assert!(bookmark.contains("count=\"4\""));
+    }
+
+    #[test]
+    fn adds_multiple_files() -> std::io::Result<()> {
+        let home = TempDir::new("recently-used")?;
+        std::fs::create_dir_all(home.join(".local/share"))?;
+
+        let files = [
+            (Path::new("/tmp/a.txt"), "text/plain"),
+            (Path::new("/tmp/b.png"), "image/png"),
+        ];
+        add_recent_files(&NativeFileSystem, &home, &files, "My App")?;
+        add_recent_files(&NativeFileSystem, &home, &files[..1], "My App")?;
+
+        let content = std::fs::read_to_string(home.join(RECENTLY_USED))?;
+        let bookmarks: Vec<&str> = xbel::bookmarks(&content).collect();
+        assert_eq!(bookmarks.len(), 2);
+        assert!(bookmarks.iter().any(|b| b.contains("image/png")));
+        assert!(bookmarks
+            .iter()
+            .any(|b| b.contains("a.txt") && b.contains("count=\"2\"")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn updates_bookmark_with_closing_application_tags() {
+        let content = fixture();
+        let uri = "file:///does/not/exist/photo.png";
+
+        let updated = add_bookmark(&content, uri, "My App", "image/png", NOW).unwrap_or_default();
+
+        let bookmark = xbel::bookmarks(&updated)
+            .find(|b| xbel::attribute(b, "href").as_deref() == Some(uri))
+            .unwrap_or_default();
+
+        assert!(bookmark.contains(
+            "<bookmark:application name=\"Image Viewer\" exec=\"&apos;eog %u&apos;\" \
+             modified=\"2024-02-10T15:00:00.000000Z\" count=\"1\"/>"
+        ));
+        assert!(bookmark.contains("name=\"My App\""));
+        assert!(!bookmark.contains("</bookmark:application>"));
+    }
+
+    #[test]
+    fn rejects_invalid_content() {
+        assert!(add_bookmark("", "file:///a", "app", "text/plain", NOW).is_none());
+    }
+}
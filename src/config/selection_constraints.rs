@@ -0,0 +1,200 @@
+use super::FileDialogLabels;
+use crate::DirectoryEntry;
+
+/// Sets which kinds of entries the user can select.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum SelectableEntries {
+    /// Files and directories can be selected.
+    #[default]
+    FilesAndDirectories,
+    /// Only files can be selected.
+    Files,
+    /// Only directories can be selected.
+    Directories,
+}
+
+/// Constraints of the selection when the dialog is in `DialogMode::PickMultiple` mode.
+///
+/// # Examples
+///
+/// ```
+/// use egui_file_dialog::{FileDialog, SelectableEntries, SelectionConstraints};
+///
+/// // Between 2 and 10 files, no folders
+/// FileDialog::new().selection_constraints(SelectionConstraints {
+///     min_items: 2,
+///     max_items: Some(10),
+///     selectable_entries: SelectableEntries::Files,
+/// });
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SelectionConstraints {
+    /// The minimum number of items the user has to select.
+    /// A value less than 1 has the same effect as 1.
+    pub min_items: usize,
+    /// The maximum number of items the user can select.
+    /// None if the number of items is not limited.
+    pub max_items: Option<usize>,
+    /// Which kinds of entries the user can select.
+    pub selectable_entries: SelectableEntries,
+}
+
+impl Default for SelectionConstraints {
+    /// Creates constraints that allow selecting any number of files and directories.
+    fn default() -> Self {
+        Self {
+            min_items: 1,
+            max_items: None,
+            selectable_entries: SelectableEntries::FilesAndDirectories,
+        }
+    }
+}
+
+impl SelectionConstraints {
+    /// Checks if the kind of the given entry can be selected.
+    pub const fn allows(&self, entry: &DirectoryEntry) -> bool {
+        match self.selectable_entries {
+            SelectableEntries::FilesAndDirectories => true,
+            SelectableEntries::Files => entry.is_file(),
+            SelectableEntries::Directories => entry.is_dir(),
+        }
+    }
+
+    /// Checks if another item can be selected when `count` items are already selected.
+    pub fn allows_more(&self, count: usize) -> bool {
+        self.max_items.is_none_or(|max| count < max)
+    }
+
+    /// Validates the given selection.
+    ///
+    /// Returns None if the selection is valid. Otherwise returns a message describing
+    /// why the selection is invalid.
+    pub(crate) fn validate<'a>(
+        &self,
+        selection: impl Iterator<Item = &'a DirectoryEntry>,
+        labels: &FileDialogLabels,
+    ) -> Option<String> {
+        let mut count = 0;
+
+        for entry in selection {
+            if !self.allows(entry) {
+                return Some(match self.selectable_entries {
+                    SelectableEntries::Directories => labels.err_only_directories.clone(),
+                    _ => labels.err_only_files.clone(),
+                });
+            }
+
+            count += 1;
+        }
+
+        if count < self.min_items.max(1) {
+            let min = self.min_items.max(1).to_string();
+            return Some(labels.format(&labels.err_too_few_items, &[("count", &min)]));
+        }
+
+        if let Some(max) = self.max_items {
+            if count > max {
+                let max = max.to_string();
+                return Some(labels.format(&labels.err_too_many_items, &[("count", &max)]));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::test_util::TempDir;
+    use crate::{FileDialogConfig, NativeFileSystem};
+
+    /// Returns a file and a directory entry, and the temporary directory containing them.
+    fn entries() -> io::Result<(DirectoryEntry, DirectoryEntry, TempDir)> {
+        let dir = TempDir::with_entries("constraints", &["file.txt"])?;
+
+        let config = FileDialogConfig::default();
+        Ok((
+            DirectoryEntry::from_path(&config, &dir.join("file.txt"), &NativeFileSystem),
+            DirectoryEntry::from_path(&config, &dir, &NativeFileSystem),
+            dir,
+        ))
+    }
+
+    fn validate(
+        constraints: &SelectionConstraints,
+        selection: &[&DirectoryEntry],
+    ) -> Option<String> {
+        constraints.validate(selection.iter().copied(), &FileDialogLabels::default())
+    }
+
+    #[test]
+    fn item_count() -> io::Result<()> {
+        let (file, _, _dir) = entries()?;
+        let labels = FileDialogLabels::default();
+        let constraints = SelectionConstraints {
+            min_items: 2,
+            max_items: Some(3),
+            selectable_entries: SelectableEntries::FilesAndDirectories,
+        };
+
+        assert_eq!(
+            validate(&constraints, &[&file]),
+            Some(labels.format(&labels.err_too_few_items, &[("count", "2")]))
+        );
+        assert_eq!(validate(&constraints, &[&file, &file]), None);
+        assert_eq!(validate(&constraints, &[&file, &file, &file]), None);
+        assert_eq!(
+            validate(&constraints, &[&file, &file, &file, &file]),
+            Some(labels.format(&labels.err_too_many_items, &[("count", "3")]))
+        );
+
+        assert!(constraints.allows_more(2));
+        assert!(!constraints.allows_more(3));
+        assert!(SelectionConstraints::default().allows_more(usize::MAX - 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_selection_needs_one_item() {
+        let constraints = SelectionConstraints {
+            min_items: 0,
+            ..SelectionConstraints::default()
+        };
+
+        assert!(validate(&constraints, &[]).is_some());
+    }
+
+    #[test]
+    fn entry_kinds() -> io::Result<()> {
+        let (file, directory, _dir) = entries()?;
+        let labels = FileDialogLabels::default();
+        let constraints = |selectable_entries| SelectionConstraints {
+            selectable_entries,
+            ..SelectionConstraints::default()
+        };
+
+        let any = constraints(SelectableEntries::FilesAndDirectories);
+        assert!(any.allows(&file) && any.allows(&directory));
+        assert_eq!(validate(&any, &[&file, &directory]), None);
+
+        let files = constraints(SelectableEntries::Files);
+        assert!(files.allows(&file) && !files.allows(&directory));
+        assert_eq!(
+            validate(&files, &[&file, &directory]),
+            Some(labels.err_only_files)
+        );
+
+        let directories = constraints(SelectableEntries::Directories);
+        assert!(!directories.allows(&file) && directories.allows(&directory));
+        assert_eq!(
+            validate(&directories, &[&directory, &file]),
+            Some(labels.err_only_directories)
+        );
+
+        Ok(())
+    }
+}
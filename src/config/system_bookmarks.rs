@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use super::{xbel, QuickAccess, QuickAccessPath};
+use crate::FileSystem;
+
+/// Location of the GTK bookmarks, relative to the home directory.
+const GTK_BOOKMARKS: &str = ".config/gtk-3.0/bookmarks";
+/// Location of the KDE places, relative to the home directory.
+const KDE_PLACES: &str = ".local/share/user-places.xbel";
+
+/// Function parsing the content of a bookmarks file.
+type BookmarkParser = fn(&str) -> Vec<QuickAccessPath>;
+
+/// Loads the bookmarks the user created in the file manager of GTK based desktops
+/// like GNOME, and KDE, from the given home directory.
+///
+/// Returns None if no bookmarks were found.
+pub fn load_system_bookmarks(
+    heading: &str,
+    file_system: &dyn FileSystem,
+    home_dir: &Path,
+    canonicalize_paths: bool,
+) -> Option<QuickAccess> {
+    let mut bookmarks = QuickAccess {
+        canonicalize_paths,
+        heading: heading.to_string(),
+        paths: Vec::new(),
+    };
+
+    let sources = [
+        (GTK_BOOKMARKS, parse_gtk_bookmarks as BookmarkParser),
+        (KDE_PLACES, parse_xbel),
+    ];
+
+    for (file, parse) in sources {
+        let Ok(content) = file_system.read_file(&home_dir.join(file)) else {
+            continue;
+        };
+
+        for bookmark in parse(&String::from_utf8_lossy(&content)) {
+            // Both desktops might have bookmarked the same directory
+            if !bookmarks.paths.iter().any(|p| p.path == bookmark.path) {
+                bookmarks.add_path(&bookmark.display_name, bookmark.path);
+            }
+        }
+    }
+
+    if bookmarks.paths.is_empty() {
+        return None;
+    }
+
+    Some(bookmarks)
+}
+
+/// Parses a GTK bookmarks file. Every line contains a `file://` URI followed by
+/// an optional label. Bookmarks of remote locations are skipped.
+fn parse_gtk_bookmarks(content: &str) -> Vec<QuickAccessPath> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (uri, label) = match line.split_once(' ') {
+                Some((uri, label)) => (uri, Some(label.trim())),
+                None => (line.trim(), None),
+            };
+
+            let path = xbel::file_uri_to_path(uri)?;
+            let display_name = label
+                .filter(|l| !l.is_empty())
+                .map_or_else(|| xbel::path_display_name(&path), str::to_string);
+
+            Some(QuickAccessPath { display_name, path })
+        })
+        .collect()
+}
+
+/// Parses a KDE `user-places.xbel` file.
+/// System items like Home or Trash, hidden items and remote locations are skipped.
+fn parse_xbel(content: &str) -> Vec<QuickAccessPath> {
+    xbel::bookmarks(content)
+        .filter(|bookmark| {
+            !bookmark.contains("<isSystemItem>true</isSystemItem>")
+                && !bookmark.contains("<IsHidden>true</IsHidden>")
+        })
+        .filter_map(|bookmark| {
+            let path = xbel::file_uri_to_path(&xbel::attribute(bookmark, "href")?)?;
+            let display_name = xbel::element(bookmark, "title")
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| xbel::path_display_name(&path));
+
+            Some(QuickAccessPath { display_name, path })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NativeFileSystem;
+
+    fn names_and_paths(quick_access: &QuickAccess) -> Vec<(&str, &Path)> {
+        quick_access
+            .paths
+            .iter()
+            .map(|p| (p.display_name.as_str(), p.path.as_path()))
+            .collect()
+    }
+
+    #[test]
+    fn loads_gtk_and_kde_bookmarks() {
+        let home = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/bookmarks/home");
+        let bookmarks = load_system_bookmarks("Bookmarks", &NativeFileSystem, &home, false);
+
+        assert_eq!(
+            bookmarks.as_ref().map(names_and_paths),
+            Some(vec![
+                ("Projects", Path::new("/home/user/Projects")),
+                ("My Music", Path::new("/home/user/Music Library")),
+                ("Photos", Path::new("/data/Photos")),
+                ("Work & Play", Path::new("/home/user/Work")),
+                ("Shared", Path::new("/srv/shared")),
+            ])
+        );
+    }
+
+    #[test]
+    fn no_bookmarks() {
+        let home = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/bookmarks/missing");
+
+        assert!(load_system_bookmarks("Bookmarks", &NativeFileSystem, &home, false).is_none());
+    }
+}
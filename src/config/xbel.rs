@@ -0,0 +1,156 @@
+//! Helpers to read and write XBEL files, used by desktop environments to store
+//! bookmarks and recently used files.
+
+use std::path::{Path, PathBuf};
+
+/// Returns an iterator over the `<bookmark>` elements of the given XBEL content.
+pub fn bookmarks(content: &str) -> impl Iterator<Item = &str> {
+    let mut rest = content;
+
+    std::iter::from_fn(move || {
+        let start = rest.find("<bookmark ")?;
+        rest = &rest[start..];
+
+        let end = rest
+            .find("</bookmark>")
+            .map_or(rest.len(), |end| end + "</bookmark>".len());
+        let bookmark = &rest[..end];
+        rest = &rest[end..];
+
+        Some(bookmark)
+    })
+}
+
+/// Returns the unescaped value of the attribute of the first tag in the given XML.
+pub fn attribute(xml: &str, name: &str) -> Option<String> {
+    let tag = &xml[..xml.find('>')?];
+    let pattern = format!(" {name}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let end = start + tag[start..].find('"')?;
+
+    Some(unescape(&tag[start..end]))
+}
+
+/// Returns the unescaped text of the first element with the given name.
+pub fn element(xml: &str, name: &str) -> Option<String> {
+    let open = format!("<{name}>");
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&format!("</{name}>"))?;
+
+    Some(unescape(xml[start..end].trim()))
+}
+
+/// Replaces the XML entities like `&amp;` with the characters they represent.
+pub fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Escapes the characters that are not allowed in XML text and attribute values.
+#[cfg(feature = "recently_used")]
+pub fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Converts a `file://` URI to a path. Returns None for other URI schemes.
+pub fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+
+    // Skip the host, which is usually empty or localhost
+    let path = &path[path.find('/')?..];
+
+    Some(PathBuf::from(percent_decode(path)))
+}
+
+/// Converts an absolute path to a `file://` URI.
+#[cfg(feature = "recently_used")]
+pub fn path_to_file_uri(path: &Path) -> String {
+    use std::fmt::Write;
+
+    let mut uri = String::from("file://");
+
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(char::from(byte));
+        } else {
+            let _ = write!(uri, "%{byte:02X}");
+        }
+    }
+
+    uri
+}
+
+/// Decodes the percent-encoded characters of a URI like `%20`.
+fn percent_decode(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+
+    while let Some(byte) = input.next() {
+        if byte != b'%' {
+            bytes.push(byte);
+            continue;
+        }
+
+        let hex = [input.next(), input.next()];
+        let decoded = match hex {
+            [Some(high), Some(low)] => std::str::from_utf8(&[high, low])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+
+        if let Some(decoded) = decoded {
+            bytes.push(decoded);
+        } else {
+            bytes.push(b'%');
+            bytes.extend(hex.into_iter().flatten());
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Returns the name of the file or directory, used if a bookmark has no title.
+pub fn path_display_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_uris() {
+        assert_eq!(
+            file_uri_to_path("file:///home/user/a%20b%C3%A4"),
+            Some(PathBuf::from("/home/user/a bä"))
+        );
+        assert_eq!(
+            file_uri_to_path("file://localhost/tmp/100%"),
+            Some(PathBuf::from("/tmp/100%"))
+        );
+        assert_eq!(file_uri_to_path("sftp://server/home"), None);
+    }
+
+    #[cfg(feature = "recently_used")]
+    #[test]
+    fn encodes_uris() {
+        let path = Path::new("/home/user/a bä&c.txt");
+        let uri = path_to_file_uri(path);
+
+        assert_eq!(uri, "file:///home/user/a%20b%C3%A4%26c.txt");
+        assert_eq!(file_uri_to_path(&uri).as_deref(), Some(path));
+    }
+}
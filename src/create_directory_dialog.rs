@@ -210,11 +210,15 @@ impl CreateDirectoryDialog {
         if let Some(mut x) = self.directory.clone() {
             x.push(self.input.as_str());
 
+            let name = [("name", self.input.as_str())];
+
             if x.is_dir() {
-                return Some(self.create_error(&labels.err_directory_exists));
+                let error = labels.format(&labels.err_directory_exists, &name);
+                return Some(self.create_error(&error));
             }
             if x.is_file() {
-                return Some(self.create_error(&labels.err_file_exists));
+                let error = labels.format(&labels.err_file_exists, &name);
+                return Some(self.create_error(&error));
             }
         } else {
             // This error should not occur because the validate_input function is only
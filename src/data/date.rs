@@ -0,0 +1,349 @@
+//! Conversion between points in time and calendar dates of the proleptic Gregorian
+//! calendar, without depending on a date and time library.
+//!
+//! Days are in UTC unless the `local_time` feature is enabled.
+
+use std::fmt::Display;
+use std::time::SystemTime;
+
+/// Number of seconds of a day.
+#[cfg(any(
+    not(feature = "local_time"),
+    feature = "serde",
+    feature = "recently_used"
+))]
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Returns the number of days between 1970-01-01 and the given date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year, month and day of the date the given number of days after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Parses a date in the format `YYYY-MM-DD` and returns the number of days since 1970-01-01.
+fn parse_days(date: &str) -> Option<i64> {
+    let mut parts = date.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+
+    let (year, month, day) = (year.parse().ok()?, month.parse().ok()?, day.parse().ok()?);
+    let days = days_from_civil(year, month, day);
+
+    // Invalid dates like 2023-02-30 do not survive the round trip
+    (civil_from_days(days) == (year, month, day)).then_some(days)
+}
+
+/// Day number of 1970-01-01 in the common era, where 0001-01-01 is day 1.
+#[cfg(feature = "local_time")]
+const EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// A day of the calendar, like `2024-05-01`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    /// Number of days since 1970-01-01.
+    days: i64,
+}
+
+impl Date {
+    /// Parses a date in the format `YYYY-MM-DD`.
+    pub fn parse(text: &str) -> Option<Self> {
+        parse_days(text.trim()).map(|days| Self { days })
+    }
+
+    /// Returns the current day.
+    #[cfg(not(feature = "local_time"))]
+    pub fn today() -> Self {
+        Self {
+            days: to_unix_time(SystemTime::now())
+                .0
+                .div_euclid(SECONDS_PER_DAY),
+        }
+    }
+
+    /// Returns the current day in local time.
+    #[cfg(feature = "local_time")]
+    pub fn today() -> Self {
+        use chrono::Datelike;
+
+        Self {
+            days: i64::from(chrono::Local::now().date_naive().num_days_from_ce())
+                - EPOCH_DAYS_FROM_CE,
+        }
+    }
+
+    /// Returns the day after this day.
+    pub const fn next(self) -> Self {
+        Self {
+            days: self.days + 1,
+        }
+    }
+
+    /// Returns the point in time this day starts.
+    #[cfg(not(feature = "local_time"))]
+    pub fn start(self) -> Option<SystemTime> {
+        from_unix_time(self.days.checked_mul(SECONDS_PER_DAY)?, 0)
+    }
+
+    /// Returns the point in time this day starts in local time.
+    #[cfg(feature = "local_time")]
+    pub fn start(self) -> Option<SystemTime> {
+        use chrono::TimeZone;
+
+        let days_from_ce = i32::try_from(self.days + EPOCH_DAYS_FROM_CE).ok()?;
+        let date = chrono::NaiveDate::from_num_days_from_ce_opt(days_from_ce)?;
+
+        chrono::Local
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .earliest()
+            .map(SystemTime::from)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = civil_from_days(self.days);
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// Splits a point in time into the seconds since the Unix epoch, rounded down,
+/// and the nanoseconds after that second.
+#[cfg(any(
+    not(feature = "local_time"),
+    feature = "serde",
+    feature = "recently_used"
+))]
+fn to_unix_time(time: SystemTime) -> (i64, u32) {
+    use std::time::UNIX_EPOCH;
+
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (
+            i64::try_from(duration.as_secs()).unwrap_or(i64::MAX),
+            duration.subsec_nanos(),
+        ),
+        Err(err) => {
+            let duration = err.duration();
+            let seconds = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
+
+            if duration.subsec_nanos() == 0 {
+                (-seconds, 0)
+            } else {
+                (-seconds - 1, 1_000_000_000 - duration.subsec_nanos())
+            }
+        }
+    }
+}
+
+/// Creates a point in time from the seconds since the Unix epoch and the nanoseconds
+/// after that second.
+#[cfg(any(not(feature = "local_time"), feature = "serde"))]
+fn from_unix_time(seconds: i64, nanos: u32) -> Option<SystemTime> {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let time = if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds.unsigned_abs()))?
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))?
+    };
+
+    time.checked_add(Duration::from_nanos(nanos.into()))
+}
+
+/// Formats a point in time as RFC 3339 timestamp in UTC, like `2024-05-01T12:30:00Z`.
+/// `subsec_digits` is the number of digits of the fraction of a second, up to 9.
+#[cfg(any(feature = "serde", feature = "recently_used"))]
+pub fn format_rfc3339(time: SystemTime, subsec_digits: usize) -> String {
+    let (seconds, nanos) = to_unix_time(time);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    let mut result = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60
+    );
+
+    if subsec_digits > 0 {
+        let fraction = format!("{nanos:09}");
+        result.push('.');
+        result.push_str(&fraction[..subsec_digits.min(fraction.len())]);
+    }
+
+    result.push('Z');
+    result
+}
+
+/// Parses an RFC 3339 timestamp like `2024-05-01T12:30:00.5+02:00`.
+#[cfg(feature = "serde")]
+pub fn parse_rfc3339(text: &str) -> Option<SystemTime> {
+    let (date, time) = text.trim().split_once(['T', 't', ' '])?;
+    let days = parse_days(date)?;
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let (time, offset) = time.split_at(time.rfind(['+', '-'])?);
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let seconds = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+
+        (
+            time,
+            if offset.starts_with('-') {
+                -seconds
+            } else {
+                seconds
+            },
+        )
+    };
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+
+    let mut parts = time.split(':').map(str::parse::<i64>);
+    let (Some(Ok(hour @ 0..=23)), Some(Ok(minute @ 0..=59)), Some(Ok(second @ 0..=60)), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        if !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        format!("{fraction:0<9}")[..9].parse().ok()?
+    };
+
+    let seconds = days * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second - offset;
+
+    from_unix_time(seconds, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    fn time(seconds: i64, nanos: u32) -> Option<SystemTime> {
+        from_unix_time(seconds, nanos)
+    }
+
+    #[test]
+    fn parses_dates() {
+        let date = Date::parse(" 2024-02-29 ");
+
+        assert_eq!(date.map(|d| d.to_string()).as_deref(), Some("2024-02-29"));
+        assert_eq!(
+            date.map(|d| d.next().to_string()).as_deref(),
+            Some("2024-03-01")
+        );
+        assert_eq!(
+            Date::parse("1969-12-31").map(Date::next),
+            Date::parse("1970-01-01")
+        );
+
+        assert_eq!(Date::parse("2023-02-29"), None);
+        assert_eq!(Date::parse("2024-13-01"), None);
+        assert_eq!(Date::parse("24-05-01"), None);
+        assert_eq!(Date::parse("2024-05-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn day_contains_now() {
+        let today = Date::today();
+        let now = SystemTime::now();
+
+        assert!(today.start().is_some_and(|start| start <= now));
+        assert!(today.next().start().is_some_and(|end| now < end));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn formats_rfc3339() {
+        let format =
+            |seconds, nanos, digits| time(seconds, nanos).map(|t| format_rfc3339(t, digits));
+
+        assert_eq!(format(0, 0, 0).as_deref(), Some("1970-01-01T00:00:00Z"));
+        assert_eq!(
+            format(1_714_566_600, 123_456_789, 6).as_deref(),
+            Some("2024-05-01T12:30:00.123456Z")
+        );
+        assert_eq!(
+            format(951_782_400, 0, 0).as_deref(),
+            Some("2000-02-29T00:00:00Z")
+        );
+        assert_eq!(format(-1, 0, 0).as_deref(), Some("1969-12-31T23:59:59Z"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parses_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), time(0, 0));
+        assert_eq!(
+            parse_rfc3339("2024-05-01T14:30:00.5+02:00"),
+            time(1_714_566_600, 500_000_000)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-05-01 07:30:00-05:00"),
+            time(1_714_566_600, 0)
+        );
+        assert_eq!(
+            parse_rfc3339("1969-12-31T23:59:59.25Z"),
+            time(-1, 250_000_000)
+        );
+
+        assert_eq!(parse_rfc3339("2023-02-29T00:00:00Z"), None);
+        assert_eq!(parse_rfc3339("2024-05-01T24:00:00Z"), None);
+        assert_eq!(parse_rfc3339("2024-05-01T12:30:00"), None);
+        assert_eq!(parse_rfc3339("2024-05-01"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_rfc3339() {
+        for (seconds, nanos) in [(0, 0), (1_714_566_600, 1), (-86_401, 999_999_999)] {
+            let time = time(seconds, nanos);
+
+            assert_eq!(
+                time.map(|t| format_rfc3339(t, 9))
+                    .as_deref()
+                    .and_then(parse_rfc3339),
+                time
+            );
+        }
+    }
+}
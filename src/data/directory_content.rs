@@ -1,5 +1,6 @@
 use crate::config::{FileDialogConfig, FileFilter};
-use crate::FileSystem;
+use crate::data::QuickFilter;
+use crate::{FileSystem, MimeType};
 use egui::mutex::Mutex;
 use std::path::{Path, PathBuf};
 use std::sync::{mpsc, Arc};
@@ -18,6 +19,10 @@ pub struct DirectoryFilter {
     pub file_filter: Option<FileFilter>,
     /// Optional file extension to filter by.
     pub filter_extension: Option<String>,
+    /// Filter by modification date and size set using the quick filter bar.
+    /// It is not applied when loading the directory, but by the filtered iterators
+    /// of the content, so that it can be changed without reloading the directory.
+    pub quick_filter: QuickFilter,
 }
 
 /// Contains the metadata of a directory item.
@@ -59,6 +64,7 @@ pub struct DirectoryEntry {
     is_directory: bool,
     is_system_file: bool,
     is_hidden: bool,
+    mime_type: Option<MimeType>,
     icon: String,
     /// If the item is marked as selected as part of a multi selection.
     pub selected: bool,
@@ -67,13 +73,25 @@ pub struct DirectoryEntry {
 impl DirectoryEntry {
     /// Creates a new directory entry from a path
     pub fn from_path(config: &FileDialogConfig, path: &Path, file_system: &dyn FileSystem) -> Self {
+        let is_directory = file_system.is_dir(path);
+        let is_file = file_system.is_file(path);
+
+        let mime_type = if is_file {
+            config
+                .mime_database
+                .detect(path, file_system, config.mime_detection)
+        } else {
+            None
+        };
+
         Self {
             path: path.to_path_buf(),
             metadata: file_system.metadata(path).unwrap_or_default(),
-            is_directory: file_system.is_dir(path),
-            is_system_file: !file_system.is_dir(path) && !file_system.is_file(path),
-            icon: gen_path_icon(config, path, file_system),
+            is_directory,
+            is_system_file: !is_directory && !is_file,
+            icon: gen_path_icon(config, path, is_directory, mime_type.as_ref()),
             is_hidden: file_system.is_path_hidden(path),
+            mime_type,
             selected: false,
         }
     }
@@ -107,6 +125,13 @@ impl DirectoryEntry {
         self.is_system_file
     }
 
+    /// Returns the detected MIME type of the file.
+    /// None is returned for directories, if MIME detection is disabled or
+    /// the MIME type could not be detected.
+    pub const fn mime_type(&self) -> Option<&MimeType> {
+        self.mime_type.as_ref()
+    }
+
     /// Returns the icon of the directory item.
     pub fn icon(&self) -> &str {
         &self.icon
@@ -190,6 +215,8 @@ pub struct DirectoryContent {
     content: Vec<DirectoryEntry>,
     /// Receiver when the content is loaded on a different thread.
     content_recv: DirectoryContentReceiver,
+    /// Filter by modification date and size applied by the filtered iterators.
+    quick_filter: QuickFilter,
 }
 
 impl Default for DirectoryContent {
@@ -198,6 +225,7 @@ impl Default for DirectoryContent {
             state: DirectoryContentState::Success,
             content: Vec::new(),
             content_recv: None,
+            quick_filter: QuickFilter::default(),
         }
     }
 }
@@ -228,11 +256,16 @@ impl DirectoryContent {
         file_system: Arc<dyn FileSystem + Sync + Send + 'static>,
         filter: DirectoryFilter,
     ) -> Self {
-        if config.load_via_thread {
+        let quick_filter = filter.quick_filter.clone();
+
+        let mut content = if config.load_via_thread {
             Self::with_thread(config, path, file_system, filter)
         } else {
             Self::without_thread(config, path, &*file_system, &filter)
-        }
+        };
+
+        content.quick_filter = quick_filter;
+        content
     }
 
     fn with_thread(
@@ -253,6 +286,7 @@ impl DirectoryContent {
             state: DirectoryContentState::Pending(SystemTime::now()),
             content: Vec::new(),
             content_recv: Some(Arc::new(Mutex::new(rx))),
+            quick_filter: QuickFilter::default(),
         }
     }
 
@@ -267,11 +301,13 @@ impl DirectoryContent {
                 state: DirectoryContentState::Success,
                 content: c,
                 content_recv: None,
+                quick_filter: QuickFilter::default(),
             },
             Err(err) => Self {
                 state: DirectoryContentState::Errored(err.to_string()),
                 content: Vec::new(),
                 content_recv: None,
+                quick_filter: QuickFilter::default(),
             },
         }
     }
@@ -323,6 +359,23 @@ impl DirectoryContent {
         &self.state
     }
 
+    /// Returns the current state of the loading process without updating it.
+    pub const fn state(&self) -> &DirectoryContentState {
+        &self.state
+    }
+
+    /// Returns an iterator over all elements of the directory content.
+    /// No filters are applied using this iterator.
+    pub fn iter(&self) -> impl Iterator<Item = &DirectoryEntry> {
+        self.content.iter()
+    }
+
+    /// Returns a mutable iterator over all elements of the directory content.
+    /// No filters are applied using this iterator.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut DirectoryEntry> {
+        self.content.iter_mut()
+    }
+
     /// Returns an iterator in the given range of the directory cotnents.
     /// No filters are applied using this iterator.
     pub fn iter_range_mut(
@@ -332,22 +385,36 @@ impl DirectoryContent {
         self.content[range].iter_mut()
     }
 
+    /// Returns the filter by modification date and size applied by the filtered iterators.
+    pub const fn quick_filter(&self) -> &QuickFilter {
+        &self.quick_filter
+    }
+
+    /// Sets the filter by modification date and size applied by the filtered iterators.
+    pub fn set_quick_filter(&mut self, quick_filter: QuickFilter) {
+        self.quick_filter = quick_filter;
+    }
+
     pub fn filtered_iter<'s>(
         &'s self,
         search_value: &'s str,
     ) -> impl Iterator<Item = &'s DirectoryEntry> + 's {
+        let quick_filter = self.quick_filter.matcher();
+
         self.content
             .iter()
-            .filter(|p| apply_search_value(p, search_value))
+            .filter(move |p| apply_search_value(p, search_value) && quick_filter.matches(p))
     }
 
     pub fn filtered_iter_mut<'s>(
         &'s mut self,
         search_value: &'s str,
     ) -> impl Iterator<Item = &'s mut DirectoryEntry> + 's {
+        let quick_filter = self.quick_filter.matcher();
+
         self.content
             .iter_mut()
-            .filter(|p| apply_search_value(p, search_value))
+            .filter(move |p| apply_search_value(p, search_value) && quick_filter.matches(p))
     }
 
     /// Marks each element in the content as unselected.
@@ -400,7 +467,7 @@ fn load_directory(
         }
 
         if let Some(file_filter) = &filter.file_filter {
-            if entry.is_file() && !(file_filter.filter)(entry.as_path()) {
+            if entry.is_file() && !file_filter.matches(entry.as_path(), config) {
                 continue;
             }
         }
@@ -436,15 +503,32 @@ fn load_directory(
 
 /// Generates the icon for the specific path.
 /// The default icon configuration is taken into account, as well as any configured
-/// file icon filters.
-fn gen_path_icon(config: &FileDialogConfig, path: &Path, file_system: &dyn FileSystem) -> String {
+/// file icon filters and MIME type icons.
+fn gen_path_icon(
+    config: &FileDialogConfig,
+    path: &Path,
+    is_directory: bool,
+    mime_type: Option<&MimeType>,
+) -> String {
     for def in &config.file_icon_filters {
         if (def.filter)(path) {
             return def.icon.clone();
         }
     }
 
-    if file_system.is_dir(path) {
+    if let Some(mime_type) = mime_type {
+        for def in &config.mime_icon_filters {
+            if def
+                .mime_types
+                .iter()
+                .any(|pattern| mime_type.matches(pattern))
+            {
+                return def.icon.clone();
+            }
+        }
+    }
+
+    if is_directory {
         config.default_folder_icon.clone()
     } else {
         config.default_file_icon.clone()
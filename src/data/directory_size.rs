@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use egui::mutex::Mutex;
+
+use super::format_bytes;
+use crate::{FileDialogConfig, FileSystem};
+
+/// Number of entries after which the intermediate result is published.
+const UPDATE_INTERVAL: usize = 256;
+
+/// The result of the size calculation of a directory.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DirectorySize {
+    /// Total size in bytes of the files counted so far.
+    pub bytes: u64,
+    /// If the calculation is still running.
+    pub calculating: bool,
+    /// If every file inside the directory has been counted.
+    /// False if the calculation is still running or was stopped because the
+    /// depth or time budget was exceeded.
+    pub complete: bool,
+}
+
+impl Display for DirectorySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.calculating {
+            write!(f, "{}…", format_bytes(self.bytes))
+        } else if self.complete {
+            f.write_str(&format_bytes(self.bytes))
+        } else {
+            write!(f, "> {}", format_bytes(self.bytes))
+        }
+    }
+}
+
+/// A request to calculate the size of a directory.
+struct Job {
+    path: PathBuf,
+    /// The generation of the job. The job is cancelled if the cached entry of its path
+    /// is removed or belongs to a newer job.
+    generation: u64,
+    max_depth: usize,
+    time_budget: Duration,
+    file_system: Arc<dyn FileSystem + Send + Sync>,
+    ctx: egui::Context,
+}
+
+/// A calculated or currently calculating size in the cache.
+#[derive(Debug, Clone, Copy)]
+struct CachedSize {
+    size: DirectorySize,
+    /// The generation of the job calculating the size.
+    generation: u64,
+}
+
+/// State shared between the calculator and the worker thread.
+#[derive(Default)]
+struct Shared {
+    /// Cache containing the calculated and currently calculating sizes.
+    sizes: Mutex<HashMap<PathBuf, CachedSize>>,
+    /// Incremented for every job, so that a job that was cancelled and requested again
+    /// is not confused with the new job.
+    generation: AtomicU64,
+}
+
+impl Shared {
+    /// Checks if the given job has not been cancelled.
+    fn is_active(&self, job: &Job) -> bool {
+        self.sizes
+            .lock()
+            .get(&job.path)
+            .is_some_and(|cached| cached.generation == job.generation)
+    }
+
+    /// Stores the result of the given job, if the job has not been cancelled in the meantime.
+    ///
+    /// Returns false if the job has been cancelled.
+    fn publish(&self, job: &Job, size: DirectorySize) -> bool {
+        match self.sizes.lock().get_mut(&job.path) {
+            Some(cached) if cached.generation == job.generation => cached.size = size,
+            _ => return false,
+        }
+
+        job.ctx.request_repaint();
+
+        true
+    }
+}
+
+/// Calculates the total size of directories recursively on a background worker thread.
+///
+/// The results are cached, and intermediate results can be queried while the calculation
+/// is running. The calculation of a directory stops when the maximum depth or the time budget
+/// configured in `FileDialogConfig` is exceeded.
+pub struct DirectorySizeCalculator {
+    shared: Arc<Shared>,
+    /// Sender to pass jobs to the worker thread.
+    /// The worker thread is started when the first job is requested.
+    sender: Mutex<Option<mpsc::Sender<Job>>>,
+}
+
+impl Default for DirectorySizeCalculator {
+    fn default() -> Self {
+        Self {
+            shared: Arc::new(Shared::default()),
+            sender: Mutex::new(None),
+        }
+    }
+}
+
+impl std::fmt::Debug for DirectorySizeCalculator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DirectorySizeCalculator")
+            .field("sizes", &*self.shared.sizes.lock())
+            .finish()
+    }
+}
+
+impl Drop for DirectorySizeCalculator {
+    fn drop(&mut self) {
+        // Stop the running job. The worker thread exits once the sender is dropped.
+        self.clear();
+    }
+}
+
+impl DirectorySizeCalculator {
+    /// Returns the size of the given directory calculated so far.
+    /// If the size is not cached, the calculation is queued on the worker thread.
+    ///
+    /// The given context is repainted every time an intermediate result is available.
+    pub fn get_or_calculate(
+        &self,
+        path: &Path,
+        config: &FileDialogConfig,
+        ctx: &egui::Context,
+    ) -> DirectorySize {
+        let mut sizes = self.shared.sizes.lock();
+
+        if let Some(cached) = sizes.get(path) {
+            return cached.size;
+        }
+
+        let size = DirectorySize {
+            calculating: true,
+            ..Default::default()
+        };
+        let generation = self.shared.generation.fetch_add(1, Ordering::Relaxed);
+
+        sizes.insert(path.to_path_buf(), CachedSize { size, generation });
+
+        let job = Job {
+            path: path.to_path_buf(),
+            generation,
+            max_depth: config.directory_size_max_depth,
+            time_budget: config.directory_size_time_budget,
+            file_system: config.file_system.clone(),
+            ctx: ctx.clone(),
+        };
+
+        drop(sizes);
+
+        // Sending only fails if the worker thread panicked
+        let _ = self
+            .sender
+            .lock()
+            .get_or_insert_with(|| {
+                let (tx, rx) = mpsc::channel();
+                let shared = self.shared.clone();
+                thread::spawn(move || run_worker(&shared, &rx));
+                tx
+            })
+            .send(job);
+
+        size
+    }
+
+    /// Cancels the running and pending calculations, except for the directories
+    /// for which `keep` returns true.
+    /// Sizes that were calculated completely or stopped by the budget remain cached.
+    pub fn cancel_except(&self, keep: impl Fn(&Path) -> bool) {
+        self.shared
+            .sizes
+            .lock()
+            .retain(|path, cached| !cached.size.calculating || keep(path));
+    }
+
+    /// Cancels all calculations and clears the cache.
+    pub fn clear(&self) {
+        self.shared.sizes.lock().clear();
+    }
+}
+
+/// Processes the jobs sent to the worker thread until the calculator is dropped.
+fn run_worker(shared: &Shared, receiver: &mpsc::Receiver<Job>) {
+    while let Ok(job) = receiver.recv() {
+        if shared.is_active(&job) {
+            calculate_size(shared, &job);
+        }
+    }
+}
+
+/// Walks the directory of the job recursively and sums up the size of every file.
+/// Symbolic links are skipped. The intermediate result is published periodically.
+fn calculate_size(shared: &Shared, job: &Job) {
+    let start = Instant::now();
+    let mut stack = vec![(job.path.clone(), 0)];
+    let mut size = DirectorySize {
+        calculating: true,
+        complete: true,
+        ..Default::default()
+    };
+    let mut counted: usize = 0;
+
+    'walk: while let Some((directory, depth)) = stack.pop() {
+        let Ok(entries) = job.file_system.read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries {
+            // Symbolic links are not followed, so that directories are neither counted
+            // twice nor walked in a loop
+            if job.file_system.is_symlink(&entry) {
+                continue;
+            }
+
+            if job.file_system.is_dir(&entry) {
+                if depth < job.max_depth {
+                    stack.push((entry, depth + 1));
+                } else {
+                    size.complete = false;
+                }
+            } else if let Some(bytes) = job.file_system.metadata(&entry).ok().and_then(|m| m.size) {
+                size.bytes += bytes;
+            }
+
+            if start.elapsed() > job.time_budget {
+                size.complete = false;
+                break 'walk;
+            }
+
+            counted += 1;
+            if counted % UPDATE_INTERVAL == 0 && !shared.publish(job, size) {
+                // The job has been cancelled
+                return;
+            }
+        }
+    }
+
+    size.calculating = false;
+    shared.publish(job, size);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::test_util::TempDir;
+
+    /// Waits until the calculation of the given directory finished.
+    fn wait_for_size(calculator: &DirectorySizeCalculator, path: &Path) -> DirectorySize {
+        let config = FileDialogConfig::default();
+        let ctx = egui::Context::default();
+        let start = Instant::now();
+
+        loop {
+            let size = calculator.get_or_calculate(path, &config, &ctx);
+
+            if !size.calculating || start.elapsed() > Duration::from_secs(10) {
+                return size;
+            }
+
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Creates a job for the given path and adds a calculating entry for it to the cache.
+    fn start_job(shared: &Shared, path: &str) -> Job {
+        let generation = shared.generation.fetch_add(1, Ordering::Relaxed);
+
+        shared.sizes.lock().insert(
+            PathBuf::from(path),
+            CachedSize {
+                size: DirectorySize {
+                    calculating: true,
+                    ..Default::default()
+                },
+                generation,
+            },
+        );
+
+        Job {
+            path: PathBuf::from(path),
+            generation,
+            max_depth: 1,
+            time_budget: Duration::from_secs(1),
+            file_system: Arc::new(crate::NativeFileSystem),
+            ctx: egui::Context::default(),
+        }
+    }
+
+    fn bytes(bytes: u64) -> DirectorySize {
+        DirectorySize {
+            bytes,
+            calculating: false,
+            complete: true,
+        }
+    }
+
+    #[test]
+    fn publishes_only_active_jobs() {
+        let shared = Shared::default();
+
+        let old_job = start_job(&shared, "/a");
+        assert!(shared.is_active(&old_job));
+        assert!(shared.publish(&old_job, bytes(1)));
+
+        // Requesting the directory again replaces the old job
+        let new_job = start_job(&shared, "/a");
+        assert!(!shared.is_active(&old_job));
+        assert!(!shared.publish(&old_job, bytes(2)));
+        assert!(shared.publish(&new_job, bytes(3)));
+
+        assert_eq!(
+            shared.sizes.lock().get(Path::new("/a")).map(|c| c.size),
+            Some(bytes(3))
+        );
+
+        // Removed jobs are cancelled
+        shared.sizes.lock().clear();
+        assert!(!shared.is_active(&new_job));
+        assert!(!shared.publish(&new_job, bytes(4)));
+        assert!(shared.sizes.lock().is_empty());
+    }
+
+    #[test]
+    fn cancels_all_except_kept_directories() {
+        let calculator = DirectorySizeCalculator::default();
+        let shared = &calculator.shared;
+
+        let kept = start_job(shared, "/kept");
+        let cancelled = start_job(shared, "/cancelled");
+        let finished = start_job(shared, "/finished");
+        assert!(shared.publish(&finished, bytes(5)));
+
+        calculator.cancel_except(|path| path == Path::new("/kept"));
+
+        assert!(shared.is_active(&kept));
+        assert!(!shared.is_active(&cancelled));
+        assert!(shared.publish(&kept, bytes(6)));
+        assert!(!shared.publish(&cancelled, bytes(7)));
+
+        // Finished calculations remain cached
+        assert_eq!(
+            shared
+                .sizes
+                .lock()
+                .get(Path::new("/finished"))
+                .map(|c| c.size),
+            Some(bytes(5))
+        );
+
+        calculator.clear();
+        assert!(!shared.is_active(&kept));
+        assert!(shared.sizes.lock().is_empty());
+    }
+
+    #[test]
+    fn calculates_size() -> io::Result<()> {
+        let dir = TempDir::new("size-calculate")?;
+        std::fs::create_dir_all(dir.join("a/b"))?;
+        std::fs::write(dir.join("file"), [0; 10])?;
+        std::fs::write(dir.join("a/file"), [0; 20])?;
+        std::fs::write(dir.join("a/b/file"), [0; 30])?;
+
+        let calculator = DirectorySizeCalculator::default();
+        assert_eq!(wait_for_size(&calculator, &dir), bytes(60));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_symlinks() -> io::Result<()> {
+        let dir = TempDir::new("size-symlinks")?;
+        std::fs::create_dir(dir.join("sub"))?;
+        std::fs::write(dir.join("sub/file"), [0; 100])?;
+        std::os::unix::fs::symlink(&dir, dir.join("sub/loop"))?;
+        std::os::unix::fs::symlink(dir.join("sub/file"), dir.join("link"))?;
+
+        let calculator = DirectorySizeCalculator::default();
+        let size = wait_for_size(&calculator, &dir);
+
+        assert_eq!(
+            size,
+            DirectorySize {
+                bytes: 100,
+                calculating: false,
+                complete: true,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -2,6 +2,9 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+#[cfg(target_os = "linux")]
+use super::mountinfo;
+
 /// Wrapper above the `sysinfo::Disk` struct.
 /// Used for helper functions and so that more flexibility is guaranteed in the future if
 /// the names of the disks are generated dynamically.
@@ -10,6 +13,9 @@ pub struct Disk {
     mount_point: PathBuf,
     display_name: String,
     is_removable: bool,
+    total_space: Option<u64>,
+    available_space: Option<u64>,
+    file_system: Option<String>,
 }
 
 impl Disk {
@@ -27,6 +33,33 @@ impl Disk {
                 mount_point.to_str().unwrap_or_default(),
             ),
             is_removable,
+            total_space: None,
+            available_space: None,
+            file_system: None,
+        }
+    }
+
+    /// Sets the total space and the available space of the disk in bytes.
+    pub const fn with_space(mut self, total_space: u64, available_space: u64) -> Self {
+        self.total_space = Some(total_space);
+        self.available_space = Some(available_space);
+        self
+    }
+
+    /// Sets the type of the file system of the disk, like `ext4`.
+    /// An empty type is ignored.
+    pub fn with_file_system(mut self, file_system: &str) -> Self {
+        self.file_system = Some(file_system.to_string()).filter(|fs| !fs.is_empty());
+        self
+    }
+
+    /// Sets the space of the disk from a `sysinfo::Disk`.
+    /// sysinfo reports a total space of 0 if the space could not be determined.
+    fn with_sysinfo_space(self, disk: &sysinfo::Disk) -> Self {
+        if disk.total_space() > 0 {
+            self.with_space(disk.total_space(), disk.available_space())
+        } else {
+            self
         }
     }
 
@@ -38,6 +71,8 @@ impl Disk {
             disk.is_removable(),
             canonicalize_paths,
         )
+        .with_sysinfo_space(disk)
+        .with_file_system(&disk.file_system().to_string_lossy())
     }
 
     /// Create a new Disk object based on its path (macos only)
@@ -59,6 +94,9 @@ impl Disk {
             mount_point,
             display_name,
             is_removable,
+            total_space: None,
+            available_space: None,
+            file_system: None,
         }
     }
 
@@ -76,6 +114,30 @@ impl Disk {
     pub const fn is_removable(&self) -> bool {
         self.is_removable
     }
+
+    /// Returns the total space of the disk in bytes, if known
+    pub const fn total_space(&self) -> Option<u64> {
+        self.total_space
+    }
+
+    /// Returns the space in bytes that is available on the disk, if known
+    pub const fn available_space(&self) -> Option<u64> {
+        self.available_space
+    }
+
+    /// Returns the type of the file system of the disk, like `ext4` or `NTFS`, if known
+    pub fn file_system(&self) -> Option<&str> {
+        self.file_system.as_deref()
+    }
+
+    /// Returns the fraction of the disk's space that is used, between 0.0 and 1.0.
+    /// Returns None if the total or available space is unknown.
+    pub fn usage(&self) -> Option<f32> {
+        let total = self.total_space.filter(|total| *total > 0)?;
+        let available = self.available_space?.min(total);
+
+        Some((total - available) as f32 / total as f32)
+    }
 }
 
 /// Wrapper above the `sysinfo::Disks` struct
@@ -187,7 +249,7 @@ extern "C" {
     pub fn GetLogicalDrives() -> u32;
 }
 
-#[cfg(all(not(windows), not(target_os = "macos")))]
+#[cfg(all(not(windows), not(target_os = "macos"), not(target_os = "linux")))]
 fn load_disks(canonicalize_paths: bool) -> Vec<Disk> {
     sysinfo::Disks::new_with_refreshed_list()
         .iter()
@@ -195,6 +257,79 @@ fn load_disks(canonicalize_paths: bool) -> Vec<Disk> {
         .collect()
 }
 
+// On Linux, sysinfo lists every mount including snap loop mounts, bind mounts and overlays.
+// The mounts are therefore read from mountinfo and filtered, while sysinfo is only used
+// to query the space of the disks.
+#[cfg(target_os = "linux")]
+fn load_disks(canonicalize_paths: bool) -> Vec<Disk> {
+    let sysinfo_disks = sysinfo::Disks::new_with_refreshed_list();
+
+    let Ok(mounts) = mountinfo::read_mountinfo(Path::new("/proc/self/mountinfo")) else {
+        return sysinfo_disks
+            .iter()
+            .map(|d| Disk::from_sysinfo_disk(d, canonicalize_paths))
+            .collect();
+    };
+
+    let labels = mountinfo::read_labels(Path::new("/dev/disk/by-label"));
+    let mut disks = Vec::new();
+
+    for mount in mountinfo::filter_mounts(mounts) {
+        if mount.is_gvfs() {
+            disks.extend(load_gvfs_disks(&mount.mount_point, canonicalize_paths));
+            continue;
+        }
+
+        // The source can be a link like /dev/mapper/root, while the labels
+        // point to the canonical device path.
+        let label = std::fs::canonicalize(&mount.source)
+            .ok()
+            .and_then(|device| labels.get(&device));
+
+        let sysinfo_disk = sysinfo_disks
+            .iter()
+            .find(|d| d.mount_point() == mount.mount_point);
+
+        // The mount point only reveals devices mounted by the desktop environment,
+        // sysinfo also detects removable devices mounted elsewhere.
+        let is_removable =
+            mount.is_removable() || sysinfo_disk.is_some_and(sysinfo::Disk::is_removable);
+
+        let mut disk = Disk::new(
+            Some(label.map_or(mount.source.as_str(), String::as_str)),
+            &mount.mount_point,
+            is_removable,
+            canonicalize_paths,
+        )
+        .with_file_system(&mount.fs_type);
+
+        if let Some(sysinfo_disk) = sysinfo_disk {
+            disk = disk.with_sysinfo_space(sysinfo_disk);
+        }
+
+        disks.push(disk);
+    }
+
+    disks
+}
+
+/// Loads the mounts of gvfs, like network shares or phones, which are the
+/// subdirectories of the gvfs FUSE mount.
+#[cfg(target_os = "linux")]
+fn load_gvfs_disks(gvfs_mount_point: &Path, canonicalize_paths: bool) -> Vec<Disk> {
+    let Ok(entries) = std::fs::read_dir(gvfs_mount_point) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| {
+            let name = mountinfo::gvfs_display_name(&entry.file_name().to_string_lossy());
+            Disk::new(Some(&name), &entry.path(), true, canonicalize_paths)
+        })
+        .collect()
+}
+
 // On macOS, add volumes from `/Volumes`
 #[cfg(target_os = "macos")]
 fn load_disks(canonicalize_paths: bool) -> Vec<Disk> {
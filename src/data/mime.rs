@@ -0,0 +1,619 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::MimeDetection;
+use crate::{FileSystem, NativeFileSystem};
+
+/// Number of bytes read from the beginning of a file to detect its MIME type by content.
+/// Large enough to contain the PE header of most Windows executables.
+const SNIFF_LENGTH: usize = 512;
+
+/// Sizes of the BMP info headers, used to tell BMP images apart from text starting with `BM`.
+const BMP_INFO_HEADER_SIZES: &[u32] = &[12, 40, 52, 56, 64, 108, 124];
+
+/// MIME types, file extensions and descriptions known by default.
+const BUILTIN_TYPES: &[(&str, &[&str], &str)] = &[
+    ("image/png", &["png"], "PNG image"),
+    ("image/jpeg", &["jpg", "jpeg"], "JPEG image"),
+    ("image/gif", &["gif"], "GIF image"),
+    ("image/bmp", &["bmp"], "Windows BMP image"),
+    ("image/webp", &["webp"], "WebP image"),
+    ("image/svg+xml", &["svg"], "SVG image"),
+    ("image/tiff", &["tif", "tiff"], "TIFF image"),
+    ("image/vnd.microsoft.icon", &["ico"], "Windows icon"),
+    ("image/x-dds", &["dds"], "DirectDraw surface"),
+    ("text/plain", &["txt", "log"], "Plain text document"),
+    ("text/markdown", &["md"], "Markdown document"),
+    ("text/csv", &["csv"], "CSV document"),
+    ("text/html", &["html", "htm"], "HTML document"),
+    ("text/css", &["css"], "CSS stylesheet"),
+    ("text/javascript", &["js"], "JavaScript program"),
+    ("text/rust", &["rs"], "Rust source code"),
+    ("text/x-python", &["py"], "Python script"),
+    ("text/x-csrc", &["c"], "C source code"),
+    ("text/x-chdr", &["h"], "C header"),
+    ("text/x-c++src", &["cpp", "cc", "cxx"], "C++ source code"),
+    ("text/x-c++hdr", &["hpp", "hh", "hxx"], "C++ header"),
+    ("application/x-shellscript", &["sh"], "Shell script"),
+    ("application/json", &["json"], "JSON document"),
+    ("application/toml", &["toml"], "TOML document"),
+    ("application/yaml", &["yaml", "yml"], "YAML document"),
+    ("application/xml", &["xml"], "XML document"),
+    ("application/rtf", &["rtf"], "RTF document"),
+    ("application/pdf", &["pdf"], "PDF document"),
+    ("application/zip", &["zip"], "Zip archive"),
+    ("application/gzip", &["gz"], "Gzip archive"),
+    ("application/x-tar", &["tar"], "Tar archive"),
+    ("application/x-7z-compressed", &["7z"], "7-zip archive"),
+    ("application/vnd.rar", &["rar"], "RAR archive"),
+    (
+        "application/vnd.sqlite3",
+        &["sqlite", "db"],
+        "SQLite database",
+    ),
+    ("application/wasm", &["wasm"], "WebAssembly module"),
+    (
+        "application/x-msdownload",
+        &["exe", "dll"],
+        "Windows executable",
+    ),
+    ("application/x-executable", &[], "Executable"),
+    ("application/octet-stream", &["bin"], "Binary data"),
+    ("audio/mpeg", &["mp3"], "MP3 audio"),
+    ("audio/wav", &["wav"], "WAV audio"),
+    ("audio/ogg", &["ogg", "oga"], "Ogg audio"),
+    ("audio/flac", &["flac"], "FLAC audio"),
+    ("video/mp4", &["mp4", "m4v"], "MPEG-4 video"),
+    ("video/x-matroska", &["mkv"], "Matroska video"),
+    ("video/webm", &["webm"], "WebM video"),
+    ("video/x-msvideo", &["avi"], "AVI video"),
+    ("video/quicktime", &["mov"], "QuickTime video"),
+    ("model/obj", &["obj"], "OBJ 3D model"),
+    ("model/stl", &["stl"], "STL 3D model"),
+    ("model/gltf+json", &["gltf"], "glTF 3D model"),
+    ("model/gltf-binary", &["glb"], "glTF binary 3D model"),
+];
+
+/// Magic bytes at the beginning of a file and the MIME type they identify.
+const MAGIC_BYTES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"II*\x00", "image/tiff"),
+    (b"MM\x00*", "image/tiff"),
+    (b"\x00\x00\x01\x00", "image/vnd.microsoft.icon"),
+    (b"DDS ", "image/x-dds"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (b"Rar!\x1a\x07", "application/vnd.rar"),
+    (b"SQLite format 3\x00", "application/vnd.sqlite3"),
+    (b"\x00asm", "application/wasm"),
+    (b"\x7fELF", "application/x-executable"),
+    (b"ID3", "audio/mpeg"),
+    (b"OggS", "audio/ogg"),
+    (b"fLaC", "audio/flac"),
+    (b"\x1a\x45\xdf\xa3", "video/x-matroska"),
+    (b"glTF", "model/gltf-binary"),
+    (b"<?xml", "application/xml"),
+    (b"#!", "application/x-shellscript"),
+];
+
+/// A MIME type like `image/png`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MimeType {
+    essence: String,
+}
+
+impl MimeType {
+    /// Creates a new MIME type from a string like `image/png`.
+    /// The string is converted to lowercase.
+    pub fn new(mime_type: &str) -> Self {
+        Self {
+            essence: mime_type.trim().to_lowercase(),
+        }
+    }
+
+    /// Returns the full MIME type, for example `image/png`.
+    pub fn as_str(&self) -> &str {
+        &self.essence
+    }
+
+    /// Returns the top-level type, for example `image` for `image/png`.
+    pub fn type_name(&self) -> &str {
+        self.essence.split('/').next().unwrap_or_default()
+    }
+
+    /// Returns the subtype, for example `png` for `image/png`.
+    pub fn subtype(&self) -> &str {
+        self.essence.split_once('/').map_or("", |(_, s)| s)
+    }
+
+    /// Checks if the MIME type matches the given pattern.
+    ///
+    /// The pattern can either be a full MIME type like `image/png`, a wildcard subtype
+    /// like `image/*` or the wildcards `*` and `*/*` matching every MIME type.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim().to_lowercase();
+
+        if pattern == "*" || pattern == "*/*" {
+            return true;
+        }
+
+        if let Some(type_name) = pattern.strip_suffix("/*") {
+            return self.type_name() == type_name;
+        }
+
+        self.essence == pattern
+    }
+}
+
+impl std::fmt::Display for MimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.essence)
+    }
+}
+
+/// Database used to detect the MIME type of files.
+///
+/// By default, the database contains a list of common file extensions and
+/// their MIME types. Use `MimeDatabase::from_shared_mime_info` to additionally load the
+/// freedesktop.org shared-mime-info database installed on the system.
+#[derive(Debug, Clone)]
+pub struct MimeDatabase {
+    /// Maps lowercase file extensions to their MIME type.
+    extensions: HashMap<String, MimeType>,
+    /// Human readable descriptions of the MIME types.
+    descriptions: HashMap<MimeType, String>,
+}
+
+impl Default for MimeDatabase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MimeDatabase {
+    /// Creates a new database containing the built-in MIME types.
+    pub fn new() -> Self {
+        let mut obj = Self {
+            extensions: HashMap::new(),
+            descriptions: HashMap::new(),
+        };
+
+        for (mime_type, extensions, description) in BUILTIN_TYPES {
+            for extension in *extensions {
+                obj.add_extension(extension, mime_type);
+            }
+
+            obj.set_description(mime_type, description);
+        }
+
+        obj
+    }
+
+    /// Creates a new database containing the built-in MIME types and the
+    /// shared-mime-info databases found in the XDG data directories.
+    ///
+    /// Directories that do not contain a shared-mime-info database are ignored.
+    pub fn from_shared_mime_info() -> Self {
+        let mut obj = Self::new();
+
+        // Load the directories with the lowest priority first, so that the
+        // entries of higher priority directories override them.
+        for dir in shared_mime_info_dirs().iter().rev() {
+            let _ = obj.load_shared_mime_info(dir, &NativeFileSystem);
+        }
+
+        obj
+    }
+
+    /// Loads a shared-mime-info database from the given directory, for example `/usr/share/mime`.
+    ///
+    /// The file extensions are read from the `globs2` file and the descriptions of the
+    /// MIME types from the XML files inside the `packages` directory.
+    /// Existing entries are overwritten.
+    pub fn load_shared_mime_info(
+        &mut self,
+        mime_dir: &Path,
+        file_system: &dyn FileSystem,
+    ) -> io::Result<()> {
+        let globs = file_system.read_file(&mime_dir.join("globs2"))?;
+        self.parse_globs2(&String::from_utf8_lossy(&globs));
+
+        let packages = mime_dir.join("packages");
+
+        if file_system.is_dir(&packages) {
+            for path in file_system.read_dir(&packages)? {
+                if path.extension().unwrap_or_default() != "xml" {
+                    continue;
+                }
+
+                if let Ok(content) = file_system.read_file(&path) {
+                    self.parse_package_descriptions(&String::from_utf8_lossy(&content));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Associates a file extension with the given MIME type.
+    pub fn add_extension(&mut self, extension: &str, mime_type: &str) {
+        self.extensions
+            .insert(extension.to_lowercase(), MimeType::new(mime_type));
+    }
+
+    /// Sets the human readable description of the given MIME type.
+    pub fn set_description(&mut self, mime_type: &str, description: &str) {
+        self.descriptions
+            .insert(MimeType::new(mime_type), description.to_string());
+    }
+
+    /// Returns the human readable description of the MIME type, if known.
+    pub fn description(&self, mime_type: &MimeType) -> Option<&str> {
+        self.descriptions.get(mime_type).map(String::as_str)
+    }
+
+    /// Returns the MIME type associated with the given file extension.
+    pub fn get_by_extension(&self, extension: &str) -> Option<&MimeType> {
+        self.extensions.get(&extension.to_lowercase())
+    }
+
+    /// Detects the MIME type of a file based on the first bytes of its content.
+    ///
+    /// Returns `text/plain` for valid UTF-8 content without null bytes and
+    /// `application/octet-stream` for other unknown content.
+    /// None is returned if the content is empty.
+    pub fn sniff_content(header: &[u8]) -> Option<MimeType> {
+        if header.is_empty() {
+            return None;
+        }
+
+        if header.len() >= 12 && header.starts_with(b"RIFF") {
+            match &header[8..12] {
+                b"WEBP" => return Some(MimeType::new("image/webp")),
+                b"WAVE" => return Some(MimeType::new("audio/wav")),
+                b"AVI " => return Some(MimeType::new("video/x-msvideo")),
+                _ => {}
+            }
+        }
+
+        if header.len() >= 12 && &header[4..8] == b"ftyp" {
+            if &header[8..10] == b"qt" {
+                return Some(MimeType::new("video/quicktime"));
+            }
+
+            return Some(MimeType::new("video/mp4"));
+        }
+
+        if is_bmp(header) {
+            return Some(MimeType::new("image/bmp"));
+        }
+
+        if is_pe_executable(header) {
+            return Some(MimeType::new("application/x-msdownload"));
+        }
+
+        for (magic, mime_type) in MAGIC_BYTES {
+            if header.starts_with(magic) {
+                return Some(MimeType::new(mime_type));
+            }
+        }
+
+        // The header might end in the middle of a multi-byte character, which is still valid.
+        let is_utf8 = match std::str::from_utf8(header) {
+            Ok(_) => true,
+            Err(err) => err.error_len().is_none(),
+        };
+
+        if is_utf8 && !header.contains(&0) {
+            Some(MimeType::new("text/plain"))
+        } else {
+            Some(MimeType::new("application/octet-stream"))
+        }
+    }
+
+    /// Detects the MIME type of the given file.
+    ///
+    /// Depending on the detection mode, the file extension is looked up in the database
+    /// and, if the extension is unknown, the beginning of the file is read using
+    /// the file system to detect the type by its content.
+    pub fn detect(
+        &self,
+        path: &Path,
+        file_system: &dyn FileSystem,
+        detection: MimeDetection,
+    ) -> Option<MimeType> {
+        if detection == MimeDetection::Disabled {
+            return None;
+        }
+
+        let by_extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.get_by_extension(ext));
+
+        if let Some(mime_type) = by_extension {
+            return Some(mime_type.clone());
+        }
+
+        if detection == MimeDetection::ExtensionAndContent {
+            if let Ok(header) = file_system.read_file_header(path, SNIFF_LENGTH) {
+                return Self::sniff_content(&header);
+            }
+        }
+
+        None
+    }
+
+    /// Parses the content of a shared-mime-info `globs2` file.
+    /// Each line has the format `weight:mime-type:glob[:flags]`.
+    /// Only simple extension globs like `*.png` are supported.
+    fn parse_globs2(&mut self, content: &str) {
+        // The file is sorted by weight, so only the first entry of an extension is used.
+        let mut seen: HashSet<String> = HashSet::new();
+
+        for line in content.lines() {
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split(':');
+            let (Some(_weight), Some(mime_type), Some(glob)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+
+            let Some(extension) = glob.strip_prefix("*.") else {
+                continue;
+            };
+
+            if extension.contains(['*', '?', '[']) {
+                continue;
+            }
+
+            let extension = extension.to_lowercase();
+
+            if !seen.insert(extension.clone()) {
+                continue;
+            }
+
+            self.add_extension(&extension, mime_type);
+        }
+    }
+
+    /// Parses the untranslated `<comment>` of each `<mime-type>` inside a
+    /// shared-mime-info package XML file.
+    fn parse_package_descriptions(&mut self, content: &str) {
+        const START: &str = "<mime-type ";
+        const END: &str = "</mime-type>";
+
+        let mut rest = content;
+
+        while let Some(start) = rest.find(START) {
+            rest = &rest[start + START.len()..];
+
+            let block = rest.find(END).map_or(rest, |end| &rest[..end]);
+
+            let mime_type = block
+                .split_once("type=\"")
+                .and_then(|(_, s)| s.split_once('"'))
+                .map(|(s, _)| s);
+
+            // Comments without attributes are the untranslated english descriptions.
+            let comment = block
+                .split_once("<comment>")
+                .and_then(|(_, s)| s.split_once("</comment>"))
+                .map(|(s, _)| s);
+
+            if let (Some(mime_type), Some(comment)) = (mime_type, comment) {
+                self.set_description(mime_type, &unescape_xml(comment));
+            }
+
+            rest = &rest[block.len()..];
+        }
+    }
+}
+
+/// Reads a little-endian `u32` at the given offset of the data.
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Checks if the header belongs to a BMP image. Besides the `BM` signature, the reserved
+/// fields must be zero and the size of the info header must be one of the known sizes.
+fn is_bmp(header: &[u8]) -> bool {
+    header.starts_with(b"BM")
+        && header.get(6..10) == Some(&[0, 0, 0, 0])
+        && read_u32_le(header, 14).is_some_and(|size| BMP_INFO_HEADER_SIZES.contains(&size))
+}
+
+/// Checks if the header belongs to a Windows executable. Besides the `MZ` signature of the
+/// DOS header, the `PE` signature must be found at the offset stored in `e_lfanew`.
+fn is_pe_executable(header: &[u8]) -> bool {
+    header.starts_with(b"MZ")
+        && read_u32_le(header, 0x3c)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .and_then(|offset| header.get(offset..offset.checked_add(4)?))
+            == Some(b"PE\0\0")
+}
+
+/// Replaces the predefined XML entities with their characters.
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Returns the `mime` directories inside the XDG data directories, ordered from
+/// the highest to the lowest priority.
+fn shared_mime_info_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| directories::BaseDirs::new().map(|d| d.home_dir().join(".local/share")));
+
+    if let Some(data_home) = data_home {
+        dirs.push(data_home.join("mime"));
+    }
+
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+
+    for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+        dirs.push(Path::new(dir).join("mime"));
+    }
+
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniff(header: &[u8]) -> Option<String> {
+        MimeDatabase::sniff_content(header).map(|m| m.to_string())
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let mime_type = MimeType::new(" Image/PNG ");
+
+        assert_eq!(mime_type.as_str(), "image/png");
+        assert_eq!(mime_type.type_name(), "image");
+        assert_eq!(mime_type.subtype(), "png");
+
+        assert!(mime_type.matches("image/png"));
+        assert!(mime_type.matches("IMAGE/*"));
+        assert!(mime_type.matches("*"));
+        assert!(mime_type.matches("*/*"));
+        assert!(!mime_type.matches("image/jpeg"));
+        assert!(!mime_type.matches("video/*"));
+        assert!(!mime_type.matches("image"));
+    }
+
+    #[test]
+    fn sniffs_content() {
+        assert_eq!(sniff(b""), None);
+        assert_eq!(
+            sniff(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR").as_deref(),
+            Some("image/png")
+        );
+        assert_eq!(
+            sniff(b"RIFF\0\0\0\0WEBPVP8 ").as_deref(),
+            Some("image/webp")
+        );
+        assert_eq!(
+            sniff(b"\0\0\0\x18ftypqt  ").as_deref(),
+            Some("video/quicktime")
+        );
+        assert_eq!(sniff(b"\0\0\0\x18ftypisom").as_deref(), Some("video/mp4"));
+        assert_eq!(sniff(b"Hello world").as_deref(), Some("text/plain"));
+        assert_eq!(
+            sniff(b"\0\x01\x02\x03").as_deref(),
+            Some("application/octet-stream")
+        );
+        // The header may end in the middle of a multi-byte character
+        assert_eq!(
+            sniff(&"Grüße".as_bytes()[..3]).as_deref(),
+            Some("text/plain")
+        );
+    }
+
+    #[test]
+    fn sniffs_bmp_only_with_valid_header() {
+        let mut bmp = b"BM\x46\0\0\0\0\0\0\0\x36\0\0\0".to_vec();
+        bmp.extend_from_slice(&40_u32.to_le_bytes());
+
+        assert_eq!(sniff(&bmp).as_deref(), Some("image/bmp"));
+        assert_eq!(
+            sniff(b"BMW owners club meeting notes").as_deref(),
+            Some("text/plain")
+        );
+    }
+
+    #[test]
+    fn sniffs_executable_only_with_pe_header() {
+        let mut exe = vec![0_u8; 0x84];
+        exe[..2].copy_from_slice(b"MZ");
+        exe[0x3c..0x40].copy_from_slice(&0x80_u32.to_le_bytes());
+        exe[0x80..0x84].copy_from_slice(b"PE\0\0");
+
+        assert_eq!(sniff(&exe).as_deref(), Some("application/x-msdownload"));
+        assert_eq!(
+            sniff(b"MZ is the abbreviation of a mountain zone").as_deref(),
+            Some("text/plain")
+        );
+
+        // The offset of the PE header points behind the header
+        exe[0x3c..0x40].copy_from_slice(&0x1000_u32.to_le_bytes());
+        assert_eq!(sniff(&exe).as_deref(), Some("application/octet-stream"));
+    }
+
+    #[test]
+    fn parses_globs2() {
+        let mut database = MimeDatabase::new();
+        database.parse_globs2(
+            "# comment\n\
+             80:image/x-custom:*.CUS\n\
+             50:application/x-other:*.cus\n\
+             50:text/x-readme:README\n\
+             50:text/x-backup:*.[oO]ld\n\
+             50:application/json:*.json:cs\n",
+        );
+
+        assert_eq!(
+            database.get_by_extension("cus"),
+            Some(&MimeType::new("image/x-custom"))
+        );
+        assert_eq!(
+            database.get_by_extension("json"),
+            Some(&MimeType::new("application/json"))
+        );
+        assert_eq!(database.get_by_extension("old"), None);
+        assert_eq!(database.get_by_extension("README"), None);
+    }
+
+    #[test]
+    fn parses_package_descriptions() {
+        let mut database = MimeDatabase::new();
+        database.parse_package_descriptions(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+  <mime-type type="image/x-custom">
+    <comment>Custom &amp; special image</comment>
+    <comment xml:lang="de">Eigenes Bild</comment>
+  </mime-type>
+  <mime-type type="application/x-undocumented">
+    <glob pattern="*.und"/>
+  </mime-type>
+  <mime-type type="text/x-other">
+    <comment>Other text</comment>
+  </mime-type>
+</mime-info>"#,
+        );
+
+        assert_eq!(
+            database.description(&MimeType::new("image/x-custom")),
+            Some("Custom & special image")
+        );
+        assert_eq!(
+            database.description(&MimeType::new("application/x-undocumented")),
+            None
+        );
+        assert_eq!(
+            database.description(&MimeType::new("text/x-other")),
+            Some("Other text")
+        );
+    }
+}
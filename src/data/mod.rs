@@ -3,9 +3,26 @@ pub use directory_content::{
     DirectoryContent, DirectoryContentState, DirectoryEntry, DirectoryFilter, Metadata,
 };
 
+pub mod date;
+
+mod directory_size;
+pub use directory_size::{DirectorySize, DirectorySizeCalculator};
+
 mod disks;
 pub use disks::{Disk, Disks};
 
+mod mime;
+pub use mime::{MimeDatabase, MimeType};
+
+#[cfg(target_os = "linux")]
+mod mountinfo;
+
+mod quick_filter;
+pub use quick_filter::{ModifiedRange, QuickFilter};
+
+mod size;
+pub use size::{format_bytes, parse_bytes};
+
 mod user_directories;
 
-pub use user_directories::UserDirectories;
+pub use user_directories::{UserDirectories, UserDirectory};
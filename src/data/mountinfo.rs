@@ -0,0 +1,393 @@
+//! Discovery of the devices mounted on Linux using `/proc/self/mountinfo`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// File system types that are not backed by a device the user is interested in.
+const PSEUDO_FILE_SYSTEMS: &[&str] = &[
+    "autofs",
+    "binfmt_misc",
+    "bpf",
+    "cgroup",
+    "cgroup2",
+    "configfs",
+    "debugfs",
+    "devpts",
+    "devtmpfs",
+    "efivarfs",
+    "fuse.portal",
+    "fuse.snapfuse",
+    "fusectl",
+    "hugetlbfs",
+    "mqueue",
+    "nsfs",
+    "overlay",
+    "proc",
+    "pstore",
+    "ramfs",
+    "rpc_pipefs",
+    "securityfs",
+    "selinuxfs",
+    "squashfs",
+    "sysfs",
+    "tmpfs",
+    "tracefs",
+];
+
+/// Network file systems that are listed even though they are not backed by a local device.
+const NETWORK_FILE_SYSTEMS: &[&str] = &["cifs", "nfs", "nfs4", "smb3", "fuse.sshfs"];
+
+/// File systems backed by block devices whose mount source is not a path in `/dev`,
+/// like the `rpool/ROOT/ubuntu` datasets of ZFS.
+const POOLED_FILE_SYSTEMS: &[&str] = &["zfs", "bcachefs"];
+
+/// File system type of the gvfs FUSE daemon. The mounts of gvfs, like network shares,
+/// are subdirectories of its mount point.
+const GVFS_FILE_SYSTEM: &str = "fuse.gvfsd-fuse";
+
+/// Directories containing system mounts that are not listed.
+const SYSTEM_MOUNT_DIRECTORIES: &[&str] = &[
+    "/boot",
+    "/dev",
+    "/proc",
+    "/run",
+    "/snap",
+    "/sys",
+    "/var/lib/docker",
+    "/var/lib/snapd",
+];
+
+/// Directories containing the mounts of removable devices.
+const REMOVABLE_MOUNT_DIRECTORIES: &[&str] = &["/media", "/run/media"];
+
+/// A single mount read from a mountinfo file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mount {
+    /// The major and minor number of the device, like `8:1`.
+    pub device: String,
+    /// The directory of the file system that forms the root of the mount.
+    /// Everything other than `/` is a bind mount of a subdirectory or a btrfs subvolume.
+    pub root: PathBuf,
+    /// The directory the file system is mounted at.
+    pub mount_point: PathBuf,
+    /// The type of the file system, like `ext4`.
+    pub fs_type: String,
+    /// The source of the mount, like `/dev/sda1`.
+    pub source: String,
+}
+
+impl Mount {
+    /// Checks if the mount is a removable device mounted by the desktop environment,
+    /// like a USB drive, or a gvfs mount.
+    /// Only the mount point is checked, devices the kernel reports as removable but that
+    /// are mounted elsewhere are not detected.
+    pub fn is_removable(&self) -> bool {
+        self.fs_type == GVFS_FILE_SYSTEM
+            || REMOVABLE_MOUNT_DIRECTORIES
+                .iter()
+                .any(|dir| self.mount_point.starts_with(dir))
+    }
+
+    /// Checks if the mount is a gvfs mount whose subdirectories are the actual mounts.
+    pub fn is_gvfs(&self) -> bool {
+        self.fs_type == GVFS_FILE_SYSTEM
+    }
+
+    /// Checks if the mount should be listed as a device.
+    fn is_device(&self) -> bool {
+        if self.is_gvfs() {
+            return true;
+        }
+
+        if PSEUDO_FILE_SYSTEMS.contains(&self.fs_type.as_str()) {
+            return false;
+        }
+
+        let backed_by_device =
+            self.source.starts_with("/dev/") && !self.source.starts_with("/dev/loop");
+
+        if !backed_by_device
+            && !POOLED_FILE_SYSTEMS.contains(&self.fs_type.as_str())
+            && !NETWORK_FILE_SYSTEMS.contains(&self.fs_type.as_str())
+        {
+            return false;
+        }
+
+        self.is_removable()
+            || !SYSTEM_MOUNT_DIRECTORIES
+                .iter()
+                .any(|dir| self.mount_point.starts_with(dir))
+    }
+}
+
+/// Reads and parses the mountinfo file at the given path, usually `/proc/self/mountinfo`.
+pub fn read_mountinfo(path: &Path) -> std::io::Result<Vec<Mount>> {
+    Ok(parse_mountinfo(&std::fs::read_to_string(path)?))
+}
+
+/// Parses the content of a mountinfo file. Invalid lines are skipped.
+///
+/// See `proc_pid_mountinfo(5)` for the format.
+pub fn parse_mountinfo(content: &str) -> Vec<Mount> {
+    content.lines().filter_map(parse_line).collect()
+}
+
+/// Parses a single line of a mountinfo file like:
+/// `36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue`
+fn parse_line(line: &str) -> Option<Mount> {
+    let (mount, file_system) = line.split_once(" - ")?;
+
+    let mut mount = mount.split(' ');
+    let device = mount.nth(2)?;
+    let root = mount.next()?;
+    let mount_point = mount.next()?;
+
+    let mut file_system = file_system.split(' ');
+    let fs_type = file_system.next()?;
+    let source = file_system.next()?;
+
+    Some(Mount {
+        device: device.to_string(),
+        root: PathBuf::from(unescape_octal(root)),
+        mount_point: PathBuf::from(unescape_octal(mount_point)),
+        fs_type: fs_type.to_string(),
+        source: unescape_octal(source),
+    })
+}
+
+/// Removes the mounts that should not be listed as devices, like pseudo file systems,
+/// snap loop mounts, bind mounts and devices that are mounted multiple times.
+///
+/// A mount is skipped if another listed mount of the same device already covers its root.
+/// Btrfs subvolumes, like `/@` and `/@home`, do not cover each other and are all listed.
+pub fn filter_mounts(mounts: Vec<Mount>) -> Vec<Mount> {
+    let mounts: Vec<Mount> = mounts.into_iter().filter(Mount::is_device).collect();
+
+    // Mounts of directories closer to the root of the file system are checked first,
+    // followed by shorter mount points, so that they are the ones that are listed.
+    let mut order: Vec<usize> = (0..mounts.len()).collect();
+    order.sort_by_key(|&i| {
+        (
+            mounts[i].root.components().count(),
+            mounts[i].mount_point.components().count(),
+        )
+    });
+
+    let mut listed = vec![false; mounts.len()];
+
+    for i in order {
+        let mount = &mounts[i];
+
+        listed[i] = mount.is_gvfs()
+            || !mounts.iter().zip(&listed).any(|(other, other_listed)| {
+                *other_listed
+                    && !other.is_gvfs()
+                    && other.device == mount.device
+                    && mount.root.starts_with(&other.root)
+            });
+    }
+
+    // A later mount at the same mount point hides the previous one,
+    // but listing the mount point once is enough.
+    let mut seen_mount_points = HashSet::new();
+
+    mounts
+        .into_iter()
+        .zip(listed)
+        .filter(|(mount, listed)| *listed && seen_mount_points.insert(mount.mount_point.clone()))
+        .map(|(mount, _)| mount)
+        .collect()
+}
+
+/// Reads the volume labels of the devices from a directory like `/dev/disk/by-label`,
+/// containing a symbolic link for every label pointing to the device.
+///
+/// Returns a map from the canonical path of the device to its label.
+pub fn read_labels(directory: &Path) -> HashMap<PathBuf, String> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return HashMap::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let device = std::fs::canonicalize(entry.path()).ok()?;
+            let label = unescape_hex(&entry.file_name().to_string_lossy());
+
+            Some((device, label))
+        })
+        .collect()
+}
+
+/// Returns the display name of a gvfs mount, like `smb-share:server=nas,share=music`.
+/// The name of the share or host is used, if available.
+pub fn gvfs_display_name(name: &str) -> String {
+    let Some((_, parameters)) = name.split_once(':') else {
+        return name.to_string();
+    };
+
+    let parameters: HashMap<&str, &str> = parameters
+        .split(',')
+        .filter_map(|p| p.split_once('='))
+        .collect();
+
+    match (
+        parameters.get("share"),
+        parameters.get("server").or_else(|| parameters.get("host")),
+    ) {
+        (Some(share), Some(server)) => format!("{share} ({server})"),
+        (None, Some(server)) => (*server).to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Replaces the octal escape sequences like `\040` used by mountinfo for
+/// spaces and other special characters.
+fn unescape_octal(value: &str) -> String {
+    unescape(value, |s| {
+        let digits = s.get(..3)?;
+        let byte = u8::from_str_radix(digits, 8).ok()?;
+        Some((byte, 3))
+    })
+}
+
+/// Replaces the hex escape sequences like `\x20` used by udev in the names of
+/// the `/dev/disk/by-label` links.
+fn unescape_hex(value: &str) -> String {
+    unescape(value, |s| {
+        let digits = s.strip_prefix('x')?.get(..2)?;
+        let byte = u8::from_str_radix(digits, 16).ok()?;
+        Some((byte, 3))
+    })
+}
+
+/// Replaces the escape sequences starting with a backslash.
+/// `decode` receives the text following the backslash and returns the decoded byte and
+/// the number of characters consumed, or None if the sequence is invalid.
+fn unescape(value: &str, decode: impl Fn(&str) -> Option<(u8, usize)>) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(pos) = rest.find('\\') {
+        bytes.extend_from_slice(&rest.as_bytes()[..pos]);
+        rest = &rest[pos + 1..];
+
+        match decode(rest) {
+            Some((byte, len)) => {
+                bytes.push(byte);
+                rest = &rest[len..];
+            }
+            None => bytes.push(b'\\'),
+        }
+    }
+
+    bytes.extend_from_slice(rest.as_bytes());
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/mountinfo")
+            .join(name)
+    }
+
+    fn mount_points(mounts: &[Mount]) -> Vec<&str> {
+        mounts
+            .iter()
+            .filter_map(|mount| mount.mount_point.to_str())
+            .collect()
+    }
+
+    #[test]
+    fn parses_fields() {
+        let mounts = parse_mountinfo(
+            "36 35 98:0 /mnt1 /mnt\\0402 rw,noatime master:1 shared:2 - ext3 /dev/root rw\n\
+             invalid line\n",
+        );
+
+        assert_eq!(
+            mounts,
+            vec![Mount {
+                device: "98:0".to_string(),
+                root: PathBuf::from("/mnt1"),
+                mount_point: PathBuf::from("/mnt 2"),
+                fs_type: "ext3".to_string(),
+                source: "/dev/root".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn filters_desktop_mounts() {
+        let mounts = read_mountinfo(&fixture("desktop.txt")).unwrap_or_default();
+        let devices = filter_mounts(mounts);
+
+        assert_eq!(
+            mount_points(&devices),
+            vec![
+                "/",
+                "/home",
+                "/mnt/nas",
+                "/media/user/USB STICK",
+                "/run/user/1000/gvfs",
+                "/run/media/user/Backup",
+            ]
+        );
+
+        let removable: Vec<bool> = devices.iter().map(Mount::is_removable).collect();
+        assert_eq!(removable, vec![false, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn filters_snap_and_container_mounts() {
+        let mounts = read_mountinfo(&fixture("snap.txt")).unwrap_or_default();
+
+        assert_eq!(mount_points(&filter_mounts(mounts)), vec!["/"]);
+    }
+
+    #[test]
+    fn lists_btrfs_subvolumes() {
+        let mounts = read_mountinfo(&fixture("btrfs.txt")).unwrap_or_default();
+
+        // Bind mounts and duplicate mounts of a subvolume are not listed
+        assert_eq!(
+            mount_points(&filter_mounts(mounts)),
+            vec!["/", "/home", "/.snapshots", "/mnt/data"]
+        );
+    }
+
+    #[test]
+    fn lists_zfs_datasets() {
+        let mounts = read_mountinfo(&fixture("zfs.txt")).unwrap_or_default();
+
+        assert_eq!(
+            mount_points(&filter_mounts(mounts)),
+            vec!["/", "/home/user", "/mnt/data"]
+        );
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        assert!(read_mountinfo(&fixture("missing.txt")).is_err());
+    }
+
+    #[test]
+    fn unescapes_labels() {
+        assert_eq!(unescape_hex("USB\\x20STICK"), "USB STICK");
+        assert_eq!(unescape_hex("back\\slash"), "back\\slash");
+    }
+
+    #[test]
+    fn gvfs_names() {
+        assert_eq!(
+            gvfs_display_name("smb-share:server=nas,share=music"),
+            "music (nas)"
+        );
+        assert_eq!(gvfs_display_name("sftp:host=example.com"), "example.com");
+        assert_eq!(gvfs_display_name("mtp:Phone"), "mtp:Phone");
+    }
+}
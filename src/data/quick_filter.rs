@@ -0,0 +1,237 @@
+use std::time::{Duration, SystemTime};
+
+use super::date::Date;
+use super::DirectoryEntry;
+
+/// Range of modification dates used by the quick filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifiedRange {
+    /// Items modified since the start of the current day.
+    Today,
+    /// Items modified within the last seven days.
+    LastSevenDays,
+    /// Items modified within a custom range of days. Both days are inclusive.
+    Custom {
+        /// First day of the range.
+        from: Option<Date>,
+        /// Last day of the range.
+        to: Option<Date>,
+    },
+}
+
+impl ModifiedRange {
+    /// Returns the lower (inclusive) and upper (exclusive) bound of the range.
+    fn bounds(self) -> (Option<SystemTime>, Option<SystemTime>) {
+        match self {
+            Self::Today => (Date::today().start(), None),
+            Self::LastSevenDays => (
+                SystemTime::now().checked_sub(Duration::from_secs(7 * 24 * 60 * 60)),
+                None,
+            ),
+            Self::Custom { from, to } => (
+                from.and_then(Date::start),
+                to.and_then(|d| d.next().start()),
+            ),
+        }
+    }
+}
+
+/// Filter to further restrict the displayed files by their modification date and size.
+/// Directories are never filtered so that the user can still navigate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickFilter {
+    /// The range of modification dates files must be in.
+    pub modified: Option<ModifiedRange>,
+    /// Minimum size of files in bytes.
+    pub min_size: Option<u64>,
+    /// Maximum size of files in bytes.
+    pub max_size: Option<u64>,
+}
+
+impl QuickFilter {
+    /// Returns true if any of the filters is set.
+    pub const fn is_active(&self) -> bool {
+        self.modified.is_some() || self.min_size.is_some() || self.max_size.is_some()
+    }
+
+    /// Prepares the filter to check entries. The bounds of the modification date
+    /// are computed once, so the matcher should be used for a single pass over the
+    /// entries of a directory.
+    pub fn matcher(&self) -> QuickFilterMatcher {
+        QuickFilterMatcher {
+            modified: self.modified.map(ModifiedRange::bounds),
+            min_size: self.min_size,
+            max_size: self.max_size,
+        }
+    }
+}
+
+/// A `QuickFilter` prepared to check entries. See `QuickFilter::matcher`.
+#[derive(Debug, Clone, Copy)]
+pub struct QuickFilterMatcher {
+    /// The lower (inclusive) and upper (exclusive) bound of the modification date.
+    modified: Option<(Option<SystemTime>, Option<SystemTime>)>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+}
+
+impl QuickFilterMatcher {
+    /// Checks if the given entry matches the filter.
+    /// Files whose metadata could not be loaded do not match an active filter.
+    pub fn matches(&self, entry: &DirectoryEntry) -> bool {
+        if entry.is_dir() {
+            return true;
+        }
+
+        let metadata = entry.metadata();
+
+        if let Some((after, before)) = self.modified {
+            let Some(modified) = metadata.last_modified else {
+                return false;
+            };
+
+            if after.is_some_and(|after| modified < after)
+                || before.is_some_and(|before| modified >= before)
+            {
+                return false;
+            }
+        }
+
+        if self.min_size.is_some() || self.max_size.is_some() {
+            let Some(size) = metadata.size else {
+                return false;
+            };
+
+            if self.min_size.is_some_and(|min| size < min)
+                || self.max_size.is_some_and(|max| size > max)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io;
+    use std::path::Path;
+
+    use super::*;
+    use crate::test_util::TempDir;
+    use crate::{FileDialogConfig, NativeFileSystem};
+
+    /// Creates a file of the given size and modification time in the given directory
+    /// and returns its directory entry.
+    fn entry(
+        dir: &Path,
+        name: &str,
+        size: u64,
+        modified: SystemTime,
+    ) -> io::Result<DirectoryEntry> {
+        let path = dir.join(name);
+        let file = File::create(&path)?;
+        file.set_len(size)?;
+        file.set_modified(modified)?;
+
+        Ok(DirectoryEntry::from_path(
+            &FileDialogConfig::default(),
+            &path,
+            &NativeFileSystem,
+        ))
+    }
+
+    fn modified(range: ModifiedRange) -> QuickFilter {
+        QuickFilter {
+            modified: Some(range),
+            ..QuickFilter::default()
+        }
+    }
+
+    #[test]
+    fn date_presets() -> io::Result<()> {
+        let dir = TempDir::new("quick-filter")?;
+        let now = SystemTime::now();
+        let start_of_today = Date::today().start().unwrap_or(now);
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        let today = entry(&dir, "today", 0, now)?;
+        let yesterday = entry(
+            &dir,
+            "yesterday",
+            0,
+            start_of_today - Duration::from_secs(1),
+        )?;
+        let last_week = entry(&dir, "last-week", 0, now - 6 * day)?;
+        let last_month = entry(&dir, "last-month", 0, now - 30 * day)?;
+
+        let filter = modified(ModifiedRange::Today);
+        assert!(filter.matcher().matches(&today));
+        assert!(!filter.matcher().matches(&yesterday));
+
+        let filter = modified(ModifiedRange::LastSevenDays);
+        assert!(filter.matcher().matches(&today));
+        assert!(filter.matcher().matches(&last_week));
+        assert!(!filter.matcher().matches(&last_month));
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_date_range_includes_both_days() -> io::Result<()> {
+        let dir = TempDir::new("quick-filter")?;
+        let from = Date::parse("2024-05-01");
+        let to = Date::parse("2024-05-03");
+        let time = |date: Option<Date>, offset: Duration| {
+            date.and_then(Date::start)
+                .map_or(SystemTime::UNIX_EPOCH, |t| t + offset)
+        };
+
+        let before = entry(
+            &dir,
+            "before",
+            0,
+            time(from, Duration::ZERO) - Duration::from_secs(1),
+        )?;
+        let first = entry(&dir, "first", 0, time(from, Duration::ZERO))?;
+        let last = entry(&dir, "last", 0, time(to, Duration::from_secs(23 * 60 * 60)))?;
+        let after = entry(&dir, "after", 0, time(to.map(Date::next), Duration::ZERO))?;
+
+        let filter = modified(ModifiedRange::Custom { from, to });
+        assert!(!filter.matcher().matches(&before));
+        assert!(filter.matcher().matches(&first));
+        assert!(filter.matcher().matches(&last));
+        assert!(!filter.matcher().matches(&after));
+
+        let filter = modified(ModifiedRange::Custom { from: None, to });
+        assert!(filter.matcher().matches(&before));
+        assert!(!filter.matcher().matches(&after));
+
+        Ok(())
+    }
+
+    #[test]
+    fn size_bounds_and_directories() -> io::Result<()> {
+        let dir = TempDir::new("quick-filter")?;
+        let small = entry(&dir, "small", 10, SystemTime::now())?;
+        let large = entry(&dir, "large", 1000, SystemTime::now())?;
+
+        let filter = QuickFilter {
+            modified: None,
+            min_size: Some(100),
+            max_size: None,
+        };
+        assert!(filter.is_active());
+        assert!(!filter.matcher().matches(&small));
+        assert!(filter.matcher().matches(&large));
+
+        let directory =
+            DirectoryEntry::from_path(&FileDialogConfig::default(), &dir, &NativeFileSystem);
+        assert!(filter.matcher().matches(&directory));
+        assert!(!QuickFilter::default().is_active());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+const KB: u64 = 1024;
+const MB: u64 = KB * 1024;
+const GB: u64 = MB * 1024;
+const TB: u64 = GB * 1024;
+
+/// Formats a file size (in bytes) into a human-readable string (e.g., KB, MB).
+///
+/// # Arguments
+/// - `bytes`: The file size in bytes.
+///
+/// # Returns
+/// A string representing the file size in an appropriate unit.
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes >= TB {
+        format!("{:.2} TB", bytes as f64 / TB as f64)
+    } else if bytes >= GB {
+        format!("{:.2} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.2} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.2} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Parses a human-readable file size like `10 MB`, `1.5g` or `512` into bytes.
+/// The units are case-insensitive and use the same base of 1024 as `format_bytes`.
+///
+/// Returns None if the input is not a valid size.
+pub fn parse_bytes(input: &str) -> Option<u64> {
+    let input = input.trim().to_lowercase();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+    let (value, unit) = input.split_at(split);
+
+    let value: f64 = value.parse().ok()?;
+    let factor = match unit.trim() {
+        "" | "b" => 1,
+        "k" | "kb" => KB,
+        "m" | "mb" => MB,
+        "g" | "gb" => GB,
+        "t" | "tb" => TB,
+        _ => return None,
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some((value * factor as f64).round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bytes() {
+        assert_eq!(parse_bytes("512"), Some(512));
+        assert_eq!(parse_bytes(" 512 b "), Some(512));
+        assert_eq!(parse_bytes("10 KB"), Some(10 * KB));
+        assert_eq!(parse_bytes("10kb"), Some(10 * KB));
+        assert_eq!(parse_bytes("1.5g"), Some(GB + GB / 2));
+        assert_eq!(parse_bytes("2 Tb"), Some(2 * TB));
+        assert_eq!(parse_bytes("0.5 m"), Some(MB / 2));
+
+        assert_eq!(parse_bytes(""), None);
+        assert_eq!(parse_bytes("MB"), None);
+        assert_eq!(parse_bytes("10 PB"), None);
+        assert_eq!(parse_bytes("1.2.3 KB"), None);
+        assert_eq!(parse_bytes("-1"), None);
+    }
+
+    #[test]
+    fn parses_formatted_bytes() {
+        for bytes in [0, 1023, 2 * KB, 5 * MB, 3 * GB, TB] {
+            assert_eq!(parse_bytes(&format_bytes(bytes)), Some(bytes));
+        }
+    }
+}
@@ -1,5 +1,44 @@
 use std::path::{Path, PathBuf};
 
+/// A well-known directory of the user, like the home or documents directory.
+/// Used to configure which user directories are listed in the Places section
+/// of the left panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UserDirectory {
+    /// The home directory of the user.
+    Home,
+    /// The desktop directory.
+    Desktop,
+    /// The documents directory.
+    Documents,
+    /// The downloads directory.
+    Downloads,
+    /// The audio or music directory.
+    Audio,
+    /// The pictures directory.
+    Pictures,
+    /// The videos directory.
+    Videos,
+    /// The templates directory (`XDG_TEMPLATES_DIR`).
+    Templates,
+    /// The public share directory (`XDG_PUBLICSHARE_DIR`).
+    Public,
+}
+
+impl UserDirectory {
+    /// The user directories listed in the Places section by default, in their default order.
+    pub const DEFAULT_PLACES: [Self; 7] = [
+        Self::Home,
+        Self::Desktop,
+        Self::Documents,
+        Self::Downloads,
+        Self::Audio,
+        Self::Pictures,
+        Self::Videos,
+    ];
+}
+
 /// Wrapper above `directories::UserDirs`.
 /// Currently only used to canonicalize the paths.
 #[derive(Default, Clone, Debug)]
@@ -12,6 +51,8 @@ pub struct UserDirectories {
     download_dir: Option<PathBuf>,
     picture_dir: Option<PathBuf>,
     video_dir: Option<PathBuf>,
+    template_dir: Option<PathBuf>,
+    public_dir: Option<PathBuf>,
 }
 
 impl UserDirectories {
@@ -33,6 +74,38 @@ impl UserDirectories {
             download_dir,
             picture_dir,
             video_dir,
+            template_dir: None,
+            public_dir: None,
+        }
+    }
+
+    /// Sets the templates directory.
+    #[must_use]
+    pub fn with_template_dir(mut self, template_dir: Option<PathBuf>) -> Self {
+        self.template_dir = template_dir;
+        self
+    }
+
+    /// Sets the public share directory.
+    #[must_use]
+    pub fn with_public_dir(mut self, public_dir: Option<PathBuf>) -> Self {
+        self.public_dir = public_dir;
+        self
+    }
+
+    /// Returns the path of the given user directory.
+    /// None if the directory is not available on this system.
+    pub fn get(&self, directory: UserDirectory) -> Option<&Path> {
+        match directory {
+            UserDirectory::Home => self.home_dir(),
+            UserDirectory::Desktop => self.desktop_dir(),
+            UserDirectory::Documents => self.document_dir(),
+            UserDirectory::Downloads => self.download_dir(),
+            UserDirectory::Audio => self.audio_dir(),
+            UserDirectory::Pictures => self.picture_dir(),
+            UserDirectory::Videos => self.video_dir(),
+            UserDirectory::Templates => self.template_dir.as_deref(),
+            UserDirectory::Public => self.public_dir.as_deref(),
         }
     }
 
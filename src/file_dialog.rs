@@ -1,19 +1,26 @@
+#[cfg(feature = "recently_used")]
+use crate::config::{add_recent_files, load_recent_files};
 use crate::config::{
-    FileDialogConfig, FileDialogKeyBindings, FileDialogLabels, FileFilter, Filter, OpeningMode,
-    PinnedFolder, QuickAccess, SaveExtension,
+    load_system_bookmarks, FileDialogConfig, FileDialogKeyBindings, FileDialogLabels, FileFilter,
+    FileFilterSpec, Filter, MimeDetection, OpeningMode, PinnedFolder, Place, QuickAccess,
+    SaveExtension, SelectionConstraints,
 };
 use crate::create_directory_dialog::CreateDirectoryDialog;
 use crate::data::{
-    DirectoryContent, DirectoryContentState, DirectoryEntry, DirectoryFilter, Disk, Disks,
-    UserDirectories,
+    format_bytes, DirectoryContent, DirectoryContentState, DirectoryEntry, DirectoryFilter,
+    DirectorySize, DirectorySizeCalculator, Disk, Disks, MimeDatabase, QuickFilter,
+    UserDirectories, UserDirectory,
 };
 use crate::modals::{FileDialogModal, ModalAction, ModalState, OverwriteFileModal};
+use crate::quick_filter_bar::QuickFilterBar;
 use crate::{FileSystem, NativeFileSystem};
 use egui::text::{CCursor, CCursorRange};
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{mpsc, Arc, Mutex, PoisonError, RwLock};
+use std::thread;
 
 /// Represents the mode the file dialog is currently in.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -24,6 +31,9 @@ pub enum DialogMode {
     /// When the dialog is currently used to select a single directory.
     PickDirectory,
 
+    /// When the dialog is currently used to select a single file or a single directory.
+    PickFileOrDirectory,
+
     /// When the dialog is currently used to select multiple files and directories.
     PickMultiple,
 
@@ -44,16 +54,66 @@ pub enum DialogState {
     Picked(PathBuf),
 
     /// The user has finished selecting multiple files and folders.
+    /// The paths are in the order the user selected or arranged them.
     PickedMultiple(Vec<PathBuf>),
 
     /// The user cancelled the dialog and didn't select anything.
     Cancelled,
 }
 
+/// Payload used when the user drags an item of the multi selection to reorder it.
+/// Contains the index of the dragged item.
+struct MultiSelectionDragPayload(usize);
+
+/// Payload used when the user drags a place in the left sidebar to reorder it.
+/// Contains the index of the dragged place.
+struct PlaceDragPayload(usize);
+
+/// Payload used when the user drags a pinned folder in the left sidebar to reorder it.
+/// Contains the index of the dragged folder.
+struct PinnedFolderDragPayload(usize);
+
+/// Payload used when the user drags a directory from the central panel to pin it.
+/// Contains the path of the dragged directory.
+struct DirectoryDragPayload(PathBuf);
+
+/// An item dropped onto the list of pinned folders.
+enum PinnedFolderDrop {
+    /// A pinned folder was moved from one index to another.
+    Move { from: usize, to: usize },
+    /// A directory of the central panel was dropped at the given index.
+    Pin { path: PathBuf, to: usize },
+}
+
+/// Contains the data remembered separately for each profile of the `FileDialog`.
+/// See `FileDialog::pick_file_with_profile`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DialogProfile {
+    /// The last directory the user visited using this profile.
+    pub last_visited_dir: Option<PathBuf>,
+    /// The last directory from which the user picked an item using this profile.
+    pub last_picked_dir: Option<PathBuf>,
+    /// The name of the file filter the user selected last.
+    pub file_filter: Option<String>,
+    /// The name of the save extension the user selected last.
+    pub save_extension: Option<String>,
+}
+
 /// Contains data of the `FileDialog` that should be stored persistently.
-#[derive(Debug, Clone)]
+///
+/// The data is versioned to be able to load data stored by previous versions.
+/// Fields missing in the stored data are set to their default values and unknown
+/// fields are ignored. Data of previous versions is migrated using
+/// `FileDialogStorage::migrate` when the dialog is opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct FileDialogStorage {
+    /// The version of the stored data. Data stored before the version was introduced
+    /// has the version 0.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub version: u32,
     /// The folders the user pinned to the left sidebar.
     pub pinned_folders: Vec<PinnedFolder>,
     /// If hidden files and folders should be listed inside the directory view.
@@ -64,19 +124,175 @@ pub struct FileDialogStorage {
     pub last_visited_dir: Option<PathBuf>,
     /// The last directory from which the user picked an item.
     pub last_picked_dir: Option<PathBuf>,
+    /// The order of the places in the left sidebar, after the user reordered them.
+    /// Places not contained are listed after the others in their configured order.
+    pub place_order: Vec<UserDirectory>,
+    /// The data remembered separately for each profile, by the key of the profile.
+    pub profiles: HashMap<String, DialogProfile>,
 }
 
 impl Default for FileDialogStorage {
     /// Creates a new object with default values
     fn default() -> Self {
         Self {
+            version: Self::VERSION,
             pinned_folders: Vec::new(),
             show_hidden: false,
             show_system_files: false,
             last_visited_dir: None,
             last_picked_dir: None,
+            place_order: Vec::new(),
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+impl FileDialogStorage {
+    /// The current version of the stored data.
+    pub const VERSION: u32 = 1;
+
+    /// Migrates data stored by a previous version to the current version.
+    ///
+    /// This is called automatically when the dialog is opened, but can also be called
+    /// directly after loading the data. Data of the current or a newer version is
+    /// left unchanged.
+    pub fn migrate(&mut self) {
+        if self.version >= Self::VERSION {
+            return;
+        }
+
+        // Version 0 is the data stored before the version was introduced.
+        // The fields added since then are set to their default values when deserializing,
+        // so no further changes are required.
+
+        self.version = Self::VERSION;
+    }
+
+    /// Checks if the settings of the user, like the pinned folders, are equal.
+    /// The directories the user visited or picked items from, including the data
+    /// of the profiles, are not compared.
+    fn settings_eq(&self, other: &Self) -> bool {
+        self.version == other.version
+            && self.pinned_folders == other.pinned_folders
+            && self.show_hidden == other.show_hidden
+            && self.show_system_files == other.show_system_files
+            && self.place_order == other.place_order
+    }
+
+    /// Copies the settings of the user, like the pinned folders, from the given storage.
+    /// See `FileDialogStorage::settings_eq`.
+    fn copy_settings_from(&mut self, other: &Self) {
+        self.version = other.version;
+        self.pinned_folders.clone_from(&other.pinned_folders);
+        self.show_hidden = other.show_hidden;
+        self.show_system_files = other.show_system_files;
+        self.place_order.clone_from(&other.place_order);
+    }
+
+    /// Checks if the directories the user visited or picked items from, including the
+    /// data of the profiles, are equal.
+    fn directories_eq(&self, other: &Self) -> bool {
+        self.last_visited_dir == other.last_visited_dir
+            && self.last_picked_dir == other.last_picked_dir
+            && self.profiles == other.profiles
+    }
+
+    /// Copies the directories the user visited or picked items from the given storage.
+    /// The profiles of the given storage are added, replacing profiles with the same key.
+    fn merge_directories_from(&mut self, other: &Self) {
+        self.last_visited_dir.clone_from(&other.last_visited_dir);
+        self.last_picked_dir.clone_from(&other.last_picked_dir);
+        self.profiles.extend(
+            other
+                .profiles
+                .iter()
+                .map(|(key, profile)| (key.clone(), profile.clone())),
+        );
+    }
+}
+
+/// A `FileDialogStorage` shared between multiple file dialogs.
+/// See `FileDialog::shared_storage`.
+///
+/// Cloning the handle returns a new handle to the same storage.
+#[derive(Debug, Clone, Default)]
+pub struct SharedStorage {
+    inner: Arc<RwLock<SharedStorageData>>,
+}
+
+/// The data behind a `SharedStorage` handle.
+#[derive(Debug, Default)]
+struct SharedStorageData {
+    /// Incremented whenever the storage is changed, so that dialogs can detect
+    /// changes without comparing the storage.
+    generation: u64,
+    storage: FileDialogStorage,
+}
+
+impl SharedStorage {
+    /// Creates a new shared storage containing the given storage.
+    pub fn new(storage: FileDialogStorage) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(SharedStorageData {
+                generation: 0,
+                storage,
+            })),
         }
     }
+
+    /// Returns a copy of the shared storage, for example to save it persistently.
+    ///
+    /// Besides the shared settings, the storage contains the directories the user last
+    /// visited or picked items from in any of the dialogs, and the profiles of all dialogs.
+    /// If multiple dialogs use a profile with the same key, the profile of the dialog
+    /// that changed it last is contained.
+    pub fn get(&self) -> FileDialogStorage {
+        self.read().storage.clone()
+    }
+
+    /// Replaces the shared storage. The dialogs using the storage apply the settings
+    /// the next time they are updated.
+    pub fn set(&self, storage: FileDialogStorage) {
+        let mut data = self.write();
+        data.storage = storage;
+        data.generation = data.generation.wrapping_add(1);
+    }
+
+    /// Returns the generation of the storage, which changes whenever the storage is changed.
+    fn generation(&self) -> u64 {
+        self.read().generation
+    }
+
+    /// Writes the settings of the given storage to the shared storage.
+    /// Returns the new generation of the shared storage.
+    fn write_settings(&self, storage: &FileDialogStorage) -> u64 {
+        let mut data = self.write();
+        data.storage.copy_settings_from(storage);
+        data.generation = data.generation.wrapping_add(1);
+        data.generation
+    }
+
+    /// Writes the directories of the given storage to the shared storage and merges its
+    /// profiles. The generation is not changed, as the dialogs keep their own directories.
+    fn write_directories(&self, storage: &FileDialogStorage) {
+        self.write().storage.merge_directories_from(storage);
+    }
+
+    /// Reads the settings of the shared storage into the given storage.
+    /// Returns the generation of the shared storage.
+    fn read_settings(&self, storage: &mut FileDialogStorage) -> u64 {
+        let data = self.read();
+        storage.copy_settings_from(&data.storage);
+        data.generation
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, SharedStorageData> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, SharedStorageData> {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
 }
 
 /// Represents a file dialog instance.
@@ -125,6 +341,9 @@ pub struct FileDialog {
     /// Custom data set by the API consumer, to track things like the purpose
     /// the file dialog was opened for.
     user_data: Option<Box<dyn Any + Send + Sync>>,
+    /// The profile of the currently open dialog.
+    /// None if the data is stored directly in `FileDialogStorage`.
+    profile: Option<String>,
     /// The currently used window ID.
     window_id: egui::Id,
 
@@ -132,8 +351,25 @@ pub struct FileDialog {
     /// These are loaded once when the dialog is created or when the `refresh()` method is called.
     user_directories: Option<UserDirectories>,
     /// The currently mounted system disks.
-    /// These are loaded once when the dialog is created or when the `refresh()` method is called.
+    /// These are loaded when the `refresh()` method is called and rescanned periodically
+    /// if `FileDialogConfig::disk_rescan_interval` is set.
     system_disks: Disks,
+    /// The bookmarks of the desktop environment's file manager.
+    /// These are loaded when the `refresh()` method is called.
+    system_bookmarks: Option<QuickAccess>,
+    /// The files recently used by any application.
+    /// These are loaded when the `refresh()` method is called.
+    #[cfg(feature = "recently_used")]
+    recent_files: Option<QuickAccess>,
+    /// The indices of the recently used files matching the file filter with the given ID.
+    /// Used to apply the file filter only when the files or the selected filter change.
+    #[cfg(feature = "recently_used")]
+    filtered_recent_files: Option<(Option<egui::Id>, Vec<usize>)>,
+    /// Time in seconds of the `egui::InputState` when the system disks were last scanned.
+    /// None if the time of the next frame should be used.
+    last_disk_scan: Option<f64>,
+    /// Receiver when the system disks are scanned on a different thread.
+    disk_scan_recv: Option<Mutex<mpsc::Receiver<Disks>>>,
 
     /// Contains the directories that the user opened. Every newly opened directory
     /// is pushed to the vector.
@@ -149,6 +385,8 @@ pub struct FileDialog {
 
     /// The dialog that is shown when the user wants to create a new directory.
     create_directory_dialog: CreateDirectoryDialog,
+    /// The bar to filter the directory content by modification date and size.
+    quick_filter_bar: QuickFilterBar,
 
     /// Whether the text edit is open for editing the current path.
     path_edit_visible: bool,
@@ -174,6 +412,14 @@ pub struct FileDialog {
     selected_file_filter: Option<egui::Id>,
     /// The save extension that the user selected.
     selected_save_extension: Option<egui::Id>,
+    /// The items the user selected in `DialogMode::PickMultiple` mode, across all directories
+    /// the user visited, in the order they were selected.
+    multi_selection: Vec<DirectoryEntry>,
+    /// If the selection state of the current directory content has been restored from
+    /// `multi_selection` after the directory was loaded.
+    multi_selection_restored: bool,
+    /// Calculates the size of the selected directories in the background.
+    directory_sizes: DirectorySizeCalculator,
 
     /// If we should scroll to the item selected by the user in the next frame.
     scroll_to_selection: bool,
@@ -181,6 +427,10 @@ pub struct FileDialog {
     search_value: String,
     /// If the search should be initialized in the next frame.
     init_search: bool,
+    /// Buffer containing the text typed by the user for the type-ahead selection.
+    type_ahead_buffer: String,
+    /// Time in seconds of the `egui::InputState` when the last type-ahead input was received.
+    type_ahead_last_input: f64,
 
     /// If any widget was focused in the last frame.
     /// This is used to prevent the dialog from closing when pressing the escape key
@@ -193,6 +443,28 @@ pub struct FileDialog {
     /// If the text input of the pinned folder being renamed should request focus in
     /// the next frame.
     rename_pinned_folder_request_focus: bool,
+    /// Buffer holding the name of a new group the user enters in the context menu
+    /// of a pinned folder.
+    new_pinned_folder_group: String,
+    /// The pinned folders that no longer exist.
+    /// These are checked when the `refresh()` method is called.
+    missing_pinned_folders: HashSet<PathBuf>,
+    /// Storage shared with other file dialogs. See `FileDialog::shared_storage`.
+    shared_storage: Option<SharedStorage>,
+    /// The storage as it was last synchronized with the shared storage.
+    /// Used to detect if the settings changed locally.
+    synced_storage: Option<FileDialogStorage>,
+    /// The generation of the shared storage when it was last synchronized.
+    /// Used to detect if the settings were changed by another dialog.
+    synced_generation: u64,
+    /// The file the storage is loaded from and automatically saved to.
+    /// See `FileDialog::storage_file`.
+    #[cfg(feature = "persistence")]
+    storage_file: Option<crate::StorageFile>,
+    /// The storage as it was last loaded from or saved to the storage file.
+    /// Used to save the storage only when it changed.
+    #[cfg(feature = "persistence")]
+    saved_storage: Option<FileDialogStorage>,
 }
 
 /// This tests if file dialog is send and sync.
@@ -242,17 +514,26 @@ impl FileDialog {
             state: DialogState::Closed,
             show_files: true,
             user_data: None,
+            profile: None,
 
             window_id: egui::Id::new("file_dialog"),
 
             user_directories: None,
             system_disks: Disks::new_empty(),
+            system_bookmarks: None,
+            #[cfg(feature = "recently_used")]
+            recent_files: None,
+            #[cfg(feature = "recently_used")]
+            filtered_recent_files: None,
+            last_disk_scan: None,
+            disk_scan_recv: None,
 
             directory_stack: Vec::new(),
             directory_offset: 0,
             directory_content: DirectoryContent::default(),
 
             create_directory_dialog: CreateDirectoryDialog::from_filesystem(file_system),
+            quick_filter_bar: QuickFilterBar::default(),
 
             path_edit_visible: false,
             path_edit_value: String::new(),
@@ -265,15 +546,29 @@ impl FileDialog {
             file_name_input_request_focus: true,
             selected_file_filter: None,
             selected_save_extension: None,
+            multi_selection: Vec::new(),
+            multi_selection_restored: false,
+            directory_sizes: DirectorySizeCalculator::default(),
 
             scroll_to_selection: false,
             search_value: String::new(),
             init_search: false,
+            type_ahead_buffer: String::new(),
+            type_ahead_last_input: 0.0,
 
             any_focused_last_frame: false,
 
             rename_pinned_folder: None,
             rename_pinned_folder_request_focus: false,
+            new_pinned_folder_group: String::new(),
+            missing_pinned_folders: HashSet::new(),
+            shared_storage: None,
+            synced_storage: None,
+            synced_generation: 0,
+            #[cfg(feature = "persistence")]
+            storage_file: None,
+            #[cfg(feature = "persistence")]
+            saved_storage: None,
         }
     }
 
@@ -347,11 +642,20 @@ impl FileDialog {
         note = "Use `pick_file` / `pick_directory` / `pick_multiple` in combination with \
                 `set_user_data` instead"
     )]
-    pub fn open(&mut self, mode: DialogMode, mut show_files: bool) {
+    pub fn open(&mut self, mode: DialogMode, show_files: bool) {
+        self.open_with_profile(mode, show_files, None);
+    }
+
+    /// Opens the file dialog in the given mode using the given profile.
+    /// See `FileDialog::open` and `FileDialog::pick_file_with_profile`.
+    fn open_with_profile(&mut self, mode: DialogMode, mut show_files: bool, profile: Option<&str>) {
         self.reset();
+        self.profile = profile.map(ToString::to_string);
+        self.sync_shared_storage();
+        self.storage.migrate();
         self.refresh();
 
-        if mode == DialogMode::PickFile {
+        if mode == DialogMode::PickFile || mode == DialogMode::PickFileOrDirectory {
             show_files = true;
         }
 
@@ -366,6 +670,7 @@ impl FileDialog {
 
         self.set_default_file_filter();
         self.set_default_save_extension();
+        self.restore_profile_selection();
 
         self.mode = mode;
         self.state = DialogState::Open;
@@ -374,7 +679,7 @@ impl FileDialog {
         self.window_id = self
             .config
             .id
-            .map_or_else(|| egui::Id::new(self.get_window_title()), |id| id);
+            .unwrap_or_else(|| egui::Id::new(self.get_window_title()));
 
         self.load_directory(&self.get_initial_directory());
     }
@@ -392,6 +697,17 @@ impl FileDialog {
         self.open(DialogMode::PickDirectory, false);
     }
 
+    /// Opens the file dialog to prompt the user to pick a directory,
+    /// using the profile with the given key.
+    ///
+    /// Each profile remembers its own last visited and picked directories, selected
+    /// file filter and save extension. See [`FileDialog::pick_file_with_profile`].
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    pub fn pick_directory_with_profile(&mut self, profile: &str) {
+        self.open_with_profile(DialogMode::PickDirectory, false, Some(profile));
+    }
+
     /// Shortcut function to open the file dialog to prompt the user to pick a file.
     /// This function resets the file dialog. Configuration variables such as
     /// `initial_directory` are retained.
@@ -403,6 +719,60 @@ impl FileDialog {
         self.open(DialogMode::PickFile, true);
     }
 
+    /// Opens the file dialog to prompt the user to pick a file,
+    /// using the profile with the given key.
+    ///
+    /// Each profile remembers its own last visited and picked directories, selected
+    /// file filter and save extension. This is useful if the same dialog is used for
+    /// different purposes, like importing meshes and exporting renders.
+    /// The data of all profiles is stored in `FileDialogStorage::profiles`.
+    ///
+    /// A profile that was not used before starts in `FileDialogConfig::initial_directory`
+    /// with the default file filter and save extension. The directories remembered
+    /// without a profile are not used.
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialog;
+    ///
+    /// let mut dialog = FileDialog::new();
+    ///
+    /// // When the user presses the "Import mesh" button
+    /// dialog.pick_file_with_profile("import_mesh");
+    ///
+    /// assert_eq!(dialog.profile(), Some("import_mesh"));
+    /// ```
+    pub fn pick_file_with_profile(&mut self, profile: &str) {
+        self.open_with_profile(DialogMode::PickFile, true, Some(profile));
+    }
+
+    /// Shortcut function to open the file dialog to prompt the user to pick either a
+    /// single file or a single directory.
+    /// This function resets the file dialog. Configuration variables such as
+    /// `initial_directory` are retained.
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    pub fn pick_file_or_directory(&mut self) {
+        // `FileDialog::open` will only be marked as private in the future.
+        #[allow(deprecated)]
+        self.open(DialogMode::PickFileOrDirectory, true);
+    }
+
+    /// Opens the file dialog to prompt the user to pick either a
+    /// single file or a single directory,
+    /// using the profile with the given key.
+    ///
+    /// Each profile remembers its own last visited and picked directories, selected
+    /// file filter and save extension. See [`FileDialog::pick_file_with_profile`].
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    pub fn pick_file_or_directory_with_profile(&mut self, profile: &str) {
+        self.open_with_profile(DialogMode::PickFileOrDirectory, true, Some(profile));
+    }
+
     /// Shortcut function to open the file dialog to prompt the user to pick multiple
     /// files and folders.
     /// This function resets the file dialog. Configuration variables such as `initial_directory`
@@ -415,6 +785,18 @@ impl FileDialog {
         self.open(DialogMode::PickMultiple, true);
     }
 
+    /// Opens the file dialog to prompt the user to pick multiple
+    /// files and folders,
+    /// using the profile with the given key.
+    ///
+    /// Each profile remembers its own last visited and picked directories, selected
+    /// file filter and save extension. See [`FileDialog::pick_file_with_profile`].
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    pub fn pick_multiple_with_profile(&mut self, profile: &str) {
+        self.open_with_profile(DialogMode::PickMultiple, true, Some(profile));
+    }
+
     /// Shortcut function to open the file dialog to prompt the user to save a file.
     /// This function resets the file dialog. Configuration variables such as
     /// `initial_directory` are retained.
@@ -426,6 +808,17 @@ impl FileDialog {
         self.open(DialogMode::SaveFile, true);
     }
 
+    /// Opens the file dialog to prompt the user to save a file,
+    /// using the profile with the given key.
+    ///
+    /// Each profile remembers its own last visited and picked directories, selected
+    /// file filter and save extension. See [`FileDialog::pick_file_with_profile`].
+    ///
+    /// The function ignores the result of the initial directory loading operation.
+    pub fn save_file_with_profile(&mut self, profile: &str) {
+        self.open_with_profile(DialogMode::SaveFile, true, Some(profile));
+    }
+
     /// The main update method that should be called every frame if the dialog is to be visible.
     ///
     /// This function has no effect if the dialog state is currently not `DialogState::Open`.
@@ -492,6 +885,80 @@ impl FileDialog {
         self
     }
 
+    /// Sets a storage that is shared with other file dialogs, so that pinned folders,
+    /// the visibility of hidden and system files and the order of the places stay
+    /// consistent between multiple dialogs that are used at the same time.
+    ///
+    /// The storage of the dialog is replaced with the shared storage. Changes of these
+    /// settings made by the user or using `FileDialog::storage_mut` are written to the
+    /// shared storage, and changes made by other dialogs are applied, while the dialog
+    /// is open. Each dialog keeps its own directories the user visited or picked items
+    /// from and its own profiles, but writes them to the shared storage as well, so that
+    /// `SharedStorage::get` returns all data to save.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::{FileDialog, SharedStorage};
+    ///
+    /// let storage = SharedStorage::default();
+    ///
+    /// let first_dialog = FileDialog::new().shared_storage(storage.clone());
+    /// let second_dialog = FileDialog::new().shared_storage(storage);
+    /// ```
+    pub fn shared_storage(mut self, storage: SharedStorage) -> Self {
+        let data = storage.read();
+        self.storage = data.storage.clone();
+        self.synced_generation = data.generation;
+        drop(data);
+
+        self.synced_storage = Some(self.storage.clone());
+        self.shared_storage = Some(storage);
+        self
+    }
+
+    /// Sets the file the storage is loaded from and saved to.
+    ///
+    /// The storage is loaded immediately, if the file exists. While the dialog is open,
+    /// the storage is saved automatically whenever the settings of the user change,
+    /// for example when the user pins a folder. The directories the user visited
+    /// are saved when the user closes the dialog.
+    ///
+    /// Use `FileDialog::save_storage` to save changes made using `FileDialog::storage_mut`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but could not be read or parsed.
+    /// The file is not used in that case, so that it is not overwritten with
+    /// the default storage.
+    #[cfg(feature = "persistence")]
+    pub fn storage_file(mut self, file: crate::StorageFile) -> std::io::Result<Self> {
+        match file.load() {
+            Ok(storage) => self.storage = storage,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        self.saved_storage = Some(self.storage.clone());
+        self.storage_file = Some(file);
+        Ok(self)
+    }
+
+    /// Saves the storage to the file set using `FileDialog::storage_file`, if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage could not be written to the file.
+    #[cfg(feature = "persistence")]
+    pub fn save_storage(&mut self) -> std::io::Result<()> {
+        if let Some(file) = &self.storage_file {
+            file.save(&self.storage)?;
+            self.saved_storage = Some(self.storage.clone());
+        }
+
+        Ok(())
+    }
+
     /// Mutably borrow internal storage.
     pub fn storage_mut(&mut self) -> &mut FileDialogStorage {
         &mut self.storage
@@ -539,6 +1006,17 @@ impl FileDialog {
         self
     }
 
+    /// Sets if the dialog should be laid out from right to left, for languages like
+    /// Arabic or Hebrew. This mirrors the side panels, the navigation buttons,
+    /// the path display and the bottom panel.
+    ///
+    /// By default, the direction is derived from the locale of the labels.
+    /// See `FileDialogLabels::is_right_to_left`.
+    pub const fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.config.right_to_left = Some(right_to_left);
+        self
+    }
+
     /// Sets the first loaded directory when the dialog opens.
     /// If the path is a file, the file's parent directory is used. If the path then has no
     /// parent directory or cannot be loaded, the user will receive an error.
@@ -627,6 +1105,75 @@ impl FileDialog {
         self
     }
 
+    /// Sets if typing while no input is focused should move the selection to the first item
+    /// whose name starts with the typed text.
+    ///
+    /// If enabled, typing no longer opens the search input and the directory content is
+    /// not filtered. Letters typed within `FileDialog::type_ahead_timeout` are combined
+    /// into a single prefix.
+    pub const fn type_ahead_selection(mut self, type_ahead_selection: bool) -> Self {
+        self.config.type_ahead_selection = type_ahead_selection;
+        self
+    }
+
+    /// Sets the time after which the text typed for the type-ahead selection is discarded.
+    ///
+    /// Has no effect when `FileDialog::type_ahead_selection` is disabled.
+    pub const fn type_ahead_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.config.type_ahead_timeout = timeout;
+        self
+    }
+
+    /// Sets if the size of directories should be calculated recursively in the background.
+    ///
+    /// The size is displayed next to the entries in the central panel and in the
+    /// information panel. Sizes that are still being calculated are marked with "…".
+    pub const fn calculate_directory_sizes(mut self, calculate_directory_sizes: bool) -> Self {
+        self.config.calculate_directory_sizes = calculate_directory_sizes;
+        self
+    }
+
+    /// Sets the interval in which the system disks are scanned while the dialog is open.
+    /// This updates the devices in the left panel when a device, like a USB drive, is added
+    /// or removed. If the device containing the currently open directory is removed,
+    /// the home directory is opened.
+    ///
+    /// Set to None to only load the disks when the dialog is opened or reloaded,
+    /// which is the default.
+    ///
+    /// While the dialog is open, a repaint of the application is requested at the end of
+    /// every interval to scan the disks, even if the user does not interact with the dialog.
+    /// A few seconds are a reasonable interval.
+    pub const fn disk_rescan_interval(mut self, interval: Option<std::time::Duration>) -> Self {
+        self.config.disk_rescan_interval = interval;
+        self
+    }
+
+    /// Sets the maximum depth of subdirectories that are included when calculating
+    /// the size of a directory.
+    pub const fn directory_size_max_depth(mut self, max_depth: usize) -> Self {
+        self.config.directory_size_max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum time spent calculating the size of a single directory.
+    /// If the time is exceeded, the size counted so far is displayed as a lower bound.
+    pub const fn directory_size_time_budget(mut self, time_budget: std::time::Duration) -> Self {
+        self.config.directory_size_time_budget = time_budget;
+        self
+    }
+
+    /// Sets the constraints of the selection when the dialog is in
+    /// `DialogMode::PickMultiple` mode, like the minimum and maximum number of items or
+    /// which kinds of entries can be selected.
+    ///
+    /// The open button is disabled and the bottom panel shows the reason as long as the
+    /// selection does not meet the constraints.
+    pub fn selection_constraints(mut self, constraints: SelectionConstraints) -> Self {
+        self.config.selection_constraints = constraints;
+        self
+    }
+
     /// Sets the icon that is used to display errors.
     pub fn err_icon(mut self, icon: &str) -> Self {
         self.config.err_icon = icon.to_string();
@@ -707,6 +1254,61 @@ impl FileDialog {
         self
     }
 
+    /// Adds a new file filter from a declarative specification.
+    ///
+    /// The specification is matched against the file system and MIME detection options
+    /// of the dialog when the filter is applied, so options set after adding the filter
+    /// are used as well. The patterns of the specification are displayed next to the
+    /// name of the filter, for example `Images (*.png, *.jpg)`.
+    ///
+    /// NOTE: The name must be unique. If a filter with the same name already exists,
+    ///       it will be overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::{FileDialog, FileFilterSpec};
+    ///
+    /// FileDialog::new()
+    ///     .add_file_filter_spec("Images", FileFilterSpec::extensions(&["png", "jpg"]))
+    ///     .add_file_filter_spec(
+    ///         "Text files",
+    ///         FileFilterSpec::Or(vec![
+    ///             FileFilterSpec::mime_types(&["text/*"]),
+    ///             FileFilterSpec::globs(&["README*"]),
+    ///         ]),
+    ///     );
+    /// ```
+    pub fn add_file_filter_spec(mut self, name: &str, spec: FileFilterSpec) -> Self {
+        self.config = self.config.add_file_filter_spec(name, spec);
+        self
+    }
+
+    /// Shortcut method to add a file filter that matches specific MIME types.
+    ///
+    /// The MIME type of a file is detected using the MIME database and detection mode
+    /// of the dialog, like the ones set using `FileDialog::mime_database` and
+    /// `FileDialog::mime_detection`, when the filter is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Display name of the filter
+    /// * `mime_types` - The MIME types to match. Wildcards like `image/*` are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialog;
+    ///
+    /// FileDialog::new()
+    ///     .add_file_filter_mime("Images", &["image/*"])
+    ///     .add_file_filter_mime("Videos", &["video/*"]);
+    /// ```
+    pub fn add_file_filter_mime(mut self, name: &str, mime_types: &[&str]) -> Self {
+        self.config = self.config.add_file_filter_mime(name, mime_types);
+        self
+    }
+
     /// Name of the file filter to be selected by default.
     ///
     /// No file filter is selected if there is no file filter with that name.
@@ -774,6 +1376,42 @@ impl FileDialog {
         self
     }
 
+    /// Sets a new icon for files with specific MIME types.
+    ///
+    /// Icons set using `FileDialog::set_file_icon` take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::FileDialog;
+    ///
+    /// FileDialog::new()
+    ///     .set_file_icon_mime("🖻", &["image/*"])
+    ///     .set_file_icon_mime("🎞", &["video/*"]);
+    /// ```
+    pub fn set_file_icon_mime(mut self, icon: &str, mime_types: &[&str]) -> Self {
+        self.config = self.config.set_file_icon_mime(icon, mime_types);
+        self
+    }
+
+    /// Sets how the MIME type of files is detected.
+    ///
+    /// By default, only the file extension is used.
+    /// See `MimeDetection` for more information.
+    pub const fn mime_detection(mut self, mime_detection: MimeDetection) -> Self {
+        self.config.mime_detection = mime_detection;
+        self
+    }
+
+    /// Sets the database used to detect the MIME type of files.
+    ///
+    /// Use `MimeDatabase::from_shared_mime_info` to use the shared-mime-info database
+    /// installed on the system.
+    pub fn mime_database(mut self, mime_database: MimeDatabase) -> Self {
+        self.config.mime_database = Arc::new(mime_database);
+        self
+    }
+
     /// Adds a new custom quick access section to the left panel.
     ///
     /// # Examples
@@ -978,6 +1616,17 @@ impl FileDialog {
         self
     }
 
+    /// Sets whether the option to show the quick filter bar inside the top panel
+    /// menu should be visible.
+    /// The quick filter bar is used to filter files by modification date and size.
+    ///
+    /// Has no effect when `FileDialog::show_top_panel` or
+    /// `FileDialog::show_menu_button` is disabled.
+    pub const fn show_quick_filter_option(mut self, show_quick_filter_option: bool) -> Self {
+        self.config.show_quick_filter_option = show_quick_filter_option;
+        self
+    }
+
     /// Sets whether the search input should be visible in the top panel.
     ///
     /// Has no effect when `FileDialog::show_top_panel` is disabled.
@@ -1009,6 +1658,98 @@ impl FileDialog {
         self
     }
 
+    /// Sets the user directories listed in the Places section and their default order.
+    /// Directories that are not available on the system are skipped.
+    ///
+    /// By default, the home, desktop, documents, downloads, audio, pictures and videos
+    /// directories are listed. The user can reorder the places by dragging them,
+    /// which is stored in `FileDialogStorage::place_order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_file_dialog::{FileDialog, UserDirectory};
+    ///
+    /// FileDialog::new()
+    ///     .places(&[UserDirectory::Home, UserDirectory::Documents, UserDirectory::Templates])
+    ///     .rename_place(UserDirectory::Home, "My Home");
+    /// ```
+    pub fn places(mut self, places: &[UserDirectory]) -> Self {
+        self.config.places = places.iter().copied().map(Place::new).collect();
+        self
+    }
+
+    /// Adds a user directory, like `UserDirectory::Templates`, to the end of the
+    /// Places section. Does nothing if the directory is already listed.
+    pub fn add_place(mut self, directory: UserDirectory) -> Self {
+        if !self.config.places.iter().any(|p| p.directory == directory) {
+            self.config.places.push(Place::new(directory));
+        }
+
+        self
+    }
+
+    /// Hides a user directory from the Places section.
+    pub fn remove_place(mut self, directory: UserDirectory) -> Self {
+        self.config.places.retain(|p| p.directory != directory);
+        self
+    }
+
+    /// Overwrites the display name of a user directory in the Places section.
+    /// Does nothing if the directory is not listed.
+    pub fn rename_place(mut self, directory: UserDirectory, display_name: &str) -> Self {
+        if let Some(place) = self
+            .config
+            .places
+            .iter_mut()
+            .find(|p| p.directory == directory)
+        {
+            place.display_name = Some(display_name.to_string());
+        }
+
+        self
+    }
+
+    /// Sets if the bookmarks the user created in the file manager of the desktop environment
+    /// should be listed in a "Bookmarks" section in the left sidebar.
+    /// GTK bookmarks (`~/.config/gtk-3.0/bookmarks`) and KDE places
+    /// (`~/.local/share/user-places.xbel`) are supported.
+    ///
+    /// The bookmarks are read using `FileSystem::read_file` when the dialog is opened
+    /// or reloaded.
+    /// Has no effect when `FileDialog::show_left_panel` is disabled.
+    pub const fn show_system_bookmarks(mut self, show_system_bookmarks: bool) -> Self {
+        self.config.show_system_bookmarks = show_system_bookmarks;
+        self
+    }
+
+    /// Sets if the files recently used by any application should be listed in a "Recent"
+    /// section in the left sidebar. The files are read from the freedesktop
+    /// `recently-used.xbel` list (`~/.local/share/recently-used.xbel`) when the dialog
+    /// is opened or reloaded.
+    ///
+    /// Only the files matching the currently selected file filter are listed.
+    /// Clicking a file opens its parent directory and selects the file.
+    /// Has no effect when `FileDialog::show_left_panel` is disabled or the dialog
+    /// only shows directories.
+    #[cfg(feature = "recently_used")]
+    pub const fn show_recent_files(mut self, show_recent_files: bool) -> Self {
+        self.config.show_recent_files = show_recent_files;
+        self
+    }
+
+    /// Sets the name of the application under which the files picked by the user are
+    /// added to the freedesktop `recently-used.xbel` list. This makes the picked files
+    /// show up in the recent files of other applications and file managers.
+    ///
+    /// The list is written using `FileSystem::write_file`.
+    /// By default, picked files are not added to the list.
+    #[cfg(feature = "recently_used")]
+    pub fn recent_files_application(mut self, application: &str) -> Self {
+        self.config.recent_files_application = Some(application.to_string());
+        self
+    }
+
     /// Sets if the "Devices" section should be visible in the left sidebar.
     /// The Devices section contains the non removable system disks.
     ///
@@ -1060,6 +1801,7 @@ impl FileDialog {
 
     /// Returns a list of the files and folders the user picked, when the dialog is in
     /// `DialogMode::PickMultiple` mode.
+    /// The paths are in the order the user selected or arranged them.
     ///
     /// None is returned when the user has not yet picked an item.
     pub fn picked_multiple(&self) -> Option<Vec<&Path>> {
@@ -1073,6 +1815,7 @@ impl FileDialog {
 
     /// Returns a list of the files and folders the user picked, when the dialog is in
     /// `DialogMode::PickMultiple` mode.
+    /// The paths are in the order the user selected or arranged them.
     /// Unlike `FileDialog::picked_multiple`, this method returns the picked paths only once
     /// and sets the dialog's state to `DialogState::Closed`.
     ///
@@ -1099,13 +1842,30 @@ impl FileDialog {
         self.selected_item.as_ref()
     }
 
-    /// Returns an iterator over the currently selected entries in [`SelectMultiple`] mode.
+    /// Returns an iterator over the currently selected entries in [`PickMultiple`] mode.
+    /// This includes the entries selected in other directories than the currently open one,
+    /// in the order the user selected them.
     ///
-    /// For the counterpart in single selection modes, see [`FileDialog::active_entry`].
+    /// For the counterpart in single selection modes, see [`FileDialog::selected_entry`].
     ///
-    /// [`SelectMultiple`]: DialogMode::SelectMultiple
+    /// [`PickMultiple`]: DialogMode::PickMultiple
     pub fn selected_entries(&self) -> impl Iterator<Item = &DirectoryEntry> {
-        self.get_dir_content_filtered_iter().filter(|p| p.selected)
+        self.multi_selection.iter()
+    }
+
+    /// Returns the size of the given directory calculated in the background so far.
+    /// Starts the calculation if the size is not yet known.
+    ///
+    /// Returns None if `FileDialogConfig::calculate_directory_sizes` is disabled.
+    pub fn directory_size(&self, ctx: &egui::Context, path: &Path) -> Option<DirectorySize> {
+        if !self.config.calculate_directory_sizes {
+            return None;
+        }
+
+        Some(
+            self.directory_sizes
+                .get_or_calculate(path, &self.config, ctx),
+        )
     }
 
     /// Returns a reference to the currently stored user data.
@@ -1149,8 +1909,15 @@ impl FileDialog {
         self.user_data = Some(Box::new(user_data));
     }
 
-    /// Returns the mode the dialog is currently in.
-    pub const fn mode(&self) -> DialogMode {
+    /// Returns the key of the profile the dialog was opened with.
+    ///
+    /// See [`FileDialog::pick_file_with_profile`].
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Returns the mode the dialog is currently in.
+    pub const fn mode(&self) -> DialogMode {
         self.mode
     }
 
@@ -1182,6 +1949,21 @@ impl FileDialog {
             ctx.move_to_top(re.response.layer_id);
         }
 
+        if self.config.type_ahead_selection && self.modals.is_empty() {
+            self.update_type_ahead(ctx);
+        }
+
+        self.sync_shared_storage();
+        self.sync_multi_selection();
+        self.update_system_disks(ctx);
+
+        // The left panel is displayed on the right side in right-to-left mode and vice versa
+        let (left_side, right_side) = if self.is_right_to_left() {
+            (egui::panel::Side::Right, egui::panel::Side::Left)
+        } else {
+            (egui::panel::Side::Left, egui::panel::Side::Right)
+        };
+
         let re = self.create_window(&mut is_open).show(ctx, |ui| {
             if !self.modals.is_empty() {
                 self.ui_update_modals(ui);
@@ -1197,7 +1979,7 @@ impl FileDialog {
             }
 
             if self.config.show_left_panel {
-                egui::SidePanel::left(self.window_id.with("left_panel"))
+                egui::SidePanel::new(left_side, self.window_id.with("left_panel"))
                     .resizable(true)
                     .default_width(150.0)
                     .width_range(90.0..=250.0)
@@ -1208,10 +1990,11 @@ impl FileDialog {
 
             // Optionally, show a custom right panel (see `update_with_custom_right_panel`)
             if let Some(f) = right_panel_fn {
-                let mut right_panel = egui::SidePanel::right(self.window_id.with("right_panel"))
-                    // Unlike the left panel, we have no control over the contents, so
-                    // we don't restrict the width. It's up to the user to make the UI presentable.
-                    .resizable(true);
+                let mut right_panel =
+                    egui::SidePanel::new(right_side, self.window_id.with("right_panel"))
+                        // Unlike the left panel, we have no control over the contents, so
+                        // we don't restrict the width. It's up to the user to make the UI presentable.
+                        .resizable(true);
                 if let Some(width) = self.config.right_panel_width {
                     right_panel = right_panel.default_width(width);
                 }
@@ -1274,6 +2057,15 @@ impl FileDialog {
         if repaint {
             ctx.request_repaint();
         }
+
+        // The dialog is only updated while it is open, so the user just picked the items
+        #[cfg(feature = "recently_used")]
+        self.add_picked_to_recent_files();
+
+        self.sync_shared_storage();
+
+        #[cfg(feature = "persistence")]
+        self.autosave_storage();
     }
 
     /// Updates the main modal background of the file dialog window.
@@ -1359,6 +2151,9 @@ impl FileDialog {
             None => match &self.mode {
                 DialogMode::PickDirectory => &self.config.labels.title_select_directory,
                 DialogMode::PickFile => &self.config.labels.title_select_file,
+                DialogMode::PickFileOrDirectory => {
+                    &self.config.labels.title_select_file_or_directory
+                }
                 DialogMode::PickMultiple => &self.config.labels.title_select_multiple,
                 DialogMode::SaveFile => &self.config.labels.title_save_file,
             },
@@ -1370,7 +2165,7 @@ impl FileDialog {
     fn ui_update_top_panel(&mut self, ui: &mut egui::Ui) {
         const BUTTON_SIZE: egui::Vec2 = egui::Vec2::new(25.0, 25.0);
 
-        ui.horizontal(|ui| {
+        ui.with_layout(self.row_layout(egui::Align::Center, false), |ui| {
             self.ui_update_nav_buttons(ui, BUTTON_SIZE);
 
             let mut path_display_width = ui.available_width();
@@ -1398,7 +2193,8 @@ impl FileDialog {
                 && (self.config.show_reload_button
                     || self.config.show_working_directory_button
                     || self.config.show_hidden_option
-                    || self.config.show_system_files_option)
+                    || self.config.show_system_files_option
+                    || self.config.show_quick_filter_option)
             {
                 ui.allocate_ui_with_layout(
                     BUTTON_SIZE,
@@ -1416,11 +2212,25 @@ impl FileDialog {
             }
         });
 
+        if self
+            .quick_filter_bar
+            .update(ui, &self.config.labels, self.window_id)
+        {
+            self.set_quick_filter(self.quick_filter_bar.filter().clone());
+        }
+
         ui.add_space(ui.ctx().style().spacing.item_spacing.y);
     }
 
     /// Updates the navigation buttons like parent or previous directory
     fn ui_update_nav_buttons(&mut self, ui: &mut egui::Ui, button_size: egui::Vec2) {
+        // The previous directory is always in reading direction
+        let (back_icon, forward_icon) = if self.is_right_to_left() {
+            ("⏵", "⏴")
+        } else {
+            ("⏴", "⏵")
+        };
+
         if self.config.show_parent_button {
             if let Some(x) = self.current_directory() {
                 if self.ui_button_sized(ui, x.parent().is_some(), button_size, "⏶", None) {
@@ -1436,7 +2246,7 @@ impl FileDialog {
                 ui,
                 self.directory_offset + 1 < self.directory_stack.len(),
                 button_size,
-                "⏴",
+                back_icon,
                 None,
             )
         {
@@ -1444,7 +2254,13 @@ impl FileDialog {
         }
 
         if self.config.show_forward_button
-            && self.ui_button_sized(ui, self.directory_offset != 0, button_size, "⏵", None)
+            && self.ui_button_sized(
+                ui,
+                self.directory_offset != 0,
+                button_size,
+                forward_icon,
+                None,
+            )
         {
             self.load_next_directory();
         }
@@ -1504,41 +2320,55 @@ impl FileDialog {
             width
         };
 
+        let right_to_left = self.is_right_to_left();
+
+        // In right-to-left mode the segments are displayed in reverse order, so that
+        // the root is on the right and the current directory on the left.
+        let mut segments: Vec<PathBuf> = self
+            .current_directory()
+            .map(|dir| {
+                dir.iter()
+                    .scan(PathBuf::new(), |path, segment| {
+                        path.push(segment);
+                        Some(path.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if right_to_left {
+            segments.reverse();
+        }
+
         egui::ScrollArea::horizontal()
             .auto_shrink([false, false])
-            .stick_to_right(true)
+            .stick_to_right(!right_to_left)
             .max_width(max_width)
             .show(ui, |ui| {
-                ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
                     ui.style_mut().spacing.item_spacing.x /= 2.5;
                     ui.style_mut().spacing.button_padding = egui::Vec2::new(5.0, 3.0);
 
-                    let mut path = PathBuf::new();
-
-                    if let Some(data) = self.current_directory().map(Path::to_path_buf) {
-                        for (i, segment) in data.iter().enumerate() {
-                            path.push(segment);
-
-                            let mut segment_str = segment.to_str().unwrap_or_default().to_string();
+                    for (i, path) in segments.iter().enumerate() {
+                        let segment = path.iter().next_back().unwrap_or_default();
+                        let mut segment_str = segment.to_str().unwrap_or_default().to_string();
 
-                            if self.is_pinned(&path) {
-                                segment_str =
-                                    format!("{} {}", &self.config.pinned_icon, segment_str);
-                            }
-
-                            if i != 0 {
-                                ui.label(self.config.directory_separator.as_str());
-                            }
+                        if self.is_pinned(path) {
+                            segment_str = format!("{} {}", &self.config.pinned_icon, segment_str);
+                        }
 
-                            let re = ui.button(segment_str);
+                        if i != 0 {
+                            ui.label(self.config.directory_separator.as_str());
+                        }
 
-                            if re.clicked() {
-                                self.load_directory(path.as_path());
-                                return;
-                            }
+                        let re = ui.button(segment_str);
 
-                            self.ui_update_central_panel_path_context_menu(&re, &path.clone());
+                        if re.clicked() {
+                            self.load_directory(path.as_path());
+                            return;
                         }
+
+                        self.ui_update_central_panel_path_context_menu(&re, path);
                     }
                 });
             });
@@ -1599,6 +2429,7 @@ impl FileDialog {
         const SEPARATOR_SPACING: f32 = 2.0;
 
         if self.config.show_reload_button && ui.button(&self.config.labels.reload).clicked() {
+            self.directory_sizes.clear();
             self.refresh();
             ui.close();
         }
@@ -1614,7 +2445,9 @@ impl FileDialog {
         }
 
         if (self.config.show_reload_button || self.config.show_working_directory_button)
-            && (self.config.show_hidden_option || self.config.show_system_files_option)
+            && (self.config.show_hidden_option
+                || self.config.show_system_files_option
+                || self.config.show_quick_filter_option)
         {
             ui.add_space(SEPARATOR_SPACING);
             ui.separator();
@@ -1644,6 +2477,17 @@ impl FileDialog {
             self.refresh();
             ui.close();
         }
+
+        if self.config.show_quick_filter_option
+            && ui
+                .checkbox(
+                    self.quick_filter_bar.open_mut(),
+                    &self.config.labels.show_quick_filter,
+                )
+                .clicked()
+        {
+            ui.close();
+        }
     }
 
     /// Updates the search input
@@ -1666,7 +2510,9 @@ impl FileDialog {
                         egui::TextEdit::singleline(&mut self.search_value),
                     );
 
-                    self.edit_search_on_text_input(ui);
+                    if !self.config.type_ahead_selection {
+                        self.edit_search_on_text_input(ui);
+                    }
 
                     if re.changed() || self.init_search {
                         self.selected_item = None;
@@ -1713,6 +2559,136 @@ impl FileDialog {
         });
     }
 
+    /// Rescans the system disks if `FileDialogConfig::disk_rescan_interval` has elapsed
+    /// since the last scan. The disks are scanned on a separate thread if
+    /// `FileDialogConfig::load_via_thread` is enabled.
+    fn update_system_disks(&mut self, ctx: &egui::Context) {
+        let Some(interval) = self.config.disk_rescan_interval else {
+            return;
+        };
+
+        if let Some(recv) = &mut self.disk_scan_recv {
+            let result = recv
+                .get_mut()
+                .map_or(Err(mpsc::TryRecvError::Disconnected), |recv| {
+                    recv.try_recv()
+                });
+
+            match result {
+                Ok(disks) => {
+                    self.disk_scan_recv = None;
+                    self.set_system_disks(disks);
+                }
+                Err(mpsc::TryRecvError::Empty) => return,
+                Err(mpsc::TryRecvError::Disconnected) => self.disk_scan_recv = None,
+            }
+        }
+
+        let time = ctx.input(|i| i.time);
+        let last_scan = *self.last_disk_scan.get_or_insert(time);
+        let elapsed = time - last_scan;
+
+        if elapsed < interval.as_secs_f64() {
+            ctx.request_repaint_after(
+                interval.saturating_sub(std::time::Duration::from_secs_f64(elapsed.max(0.0))),
+            );
+            return;
+        }
+
+        self.last_disk_scan = Some(time);
+        ctx.request_repaint_after(interval);
+
+        let canonicalize_paths = self.config.canonicalize_paths;
+
+        if self.config.load_via_thread {
+            let (tx, rx) = mpsc::channel();
+            let file_system = self.config.file_system.clone();
+            let ctx = ctx.clone();
+
+            thread::spawn(move || {
+                let _ = tx.send(file_system.get_disks(canonicalize_paths));
+                ctx.request_repaint();
+            });
+
+            self.disk_scan_recv = Some(Mutex::new(rx));
+        } else {
+            let disks = self.config.file_system.get_disks(canonicalize_paths);
+            self.set_system_disks(disks);
+        }
+    }
+
+    /// Replaces the system disks with the result of a rescan.
+    /// If the device containing the currently open directory was removed,
+    /// the home directory or the first available device is opened instead.
+    fn set_system_disks(&mut self, disks: Disks) {
+        let removed = self.get_current_device().is_some_and(|device| {
+            !disks
+                .iter()
+                .any(|disk| disk.mount_point() == device.mount_point())
+        });
+
+        self.system_disks = disks;
+
+        if !removed {
+            return;
+        }
+
+        let fallback = self
+            .user_directories
+            .as_ref()
+            .and_then(|dirs| dirs.home_dir())
+            .or_else(|| self.system_disks.iter().next().map(Disk::mount_point))
+            .map(Path::to_path_buf);
+
+        if let Some(path) = fallback {
+            self.load_directory(&path);
+        }
+    }
+
+    /// Collects text input without shortcut modifiers, if no other input is focused,
+    /// and moves the selection to the first item starting with the typed text.
+    fn update_type_ahead(&mut self, ctx: &egui::Context) {
+        if ctx.memory(|mem| mem.focused().is_some()) {
+            return;
+        }
+
+        let (text, time) = ctx.input(|inp| {
+            // We stop if any modifier is active besides only shift
+            if inp.modifiers.any() && !inp.modifiers.shift_only() {
+                return (String::new(), inp.time);
+            }
+
+            let text: String = inp
+                .events
+                .iter()
+                .filter_map(|ev| match ev {
+                    egui::Event::Text(t) => Some(t.as_str()),
+                    _ => None,
+                })
+                .collect();
+
+            (text, inp.time)
+        });
+
+        if !text.is_empty() {
+            self.type_ahead_input(&text, time);
+        }
+    }
+
+    /// Appends the typed text to the type-ahead buffer and selects the matching item.
+    /// The buffer is cleared first if the last input is longer ago than
+    /// `FileDialog::type_ahead_timeout`. `time` is the current time in seconds.
+    fn type_ahead_input(&mut self, text: &str, time: f64) {
+        if time - self.type_ahead_last_input > self.config.type_ahead_timeout.as_secs_f64() {
+            self.type_ahead_buffer.clear();
+        }
+
+        self.type_ahead_last_input = time;
+        self.type_ahead_buffer.push_str(text);
+
+        self.select_type_ahead_item();
+    }
+
     /// Updates the left panel of the dialog. Including the list of the user directories (Places)
     /// and system disks (Devices, Removable Devices).
     fn ui_update_left_panel(&mut self, ui: &mut egui::Ui) {
@@ -1743,6 +2719,24 @@ impl FileDialog {
 
                     self.config.quick_accesses = quick_accesses;
 
+                    if self.config.show_system_bookmarks {
+                        if let Some(bookmarks) = self.system_bookmarks.take() {
+                            ui.add_space(spacing);
+                            self.ui_update_quick_access(ui, &bookmarks);
+                            spacing = ui.ctx().style().spacing.item_spacing.y * SPACING_MULTIPLIER;
+
+                            self.system_bookmarks = Some(bookmarks);
+                        }
+                    }
+
+                    #[cfg(feature = "recently_used")]
+                    if self.config.show_recent_files
+                        && self.show_files
+                        && self.ui_update_recent_files(ui, spacing)
+                    {
+                        spacing = ui.ctx().style().spacing.item_spacing.y * SPACING_MULTIPLIER;
+                    }
+
                     // Update native quick access sections
                     if self.config.show_places && self.ui_update_user_directories(ui, spacing) {
                         spacing = ui.ctx().style().spacing.item_spacing.y * SPACING_MULTIPLIER;
@@ -1772,7 +2766,7 @@ impl FileDialog {
     fn ui_update_left_panel_entry(
         &mut self,
         ui: &mut egui::Ui,
-        display_name: &str,
+        display_name: impl Into<egui::WidgetText>,
         path: &Path,
     ) -> egui::Response {
         let response = ui.selectable_label(self.current_directory() == Some(path), display_name);
@@ -1793,36 +2787,290 @@ impl FileDialog {
         }
     }
 
+    /// Updates the list of the recently used files.
+    /// Only the files matching the currently selected file filter are listed.
+    ///
+    /// Returns true if at least one file was included in the list and the
+    /// heading is visible. If no file was listed, false is returned.
+    #[cfg(feature = "recently_used")]
+    fn ui_update_recent_files(&mut self, ui: &mut egui::Ui, spacing: f32) -> bool {
+        let Some(recent_files) = self.recent_files.take() else {
+            return false;
+        };
+
+        let filter_id = self.selected_file_filter;
+
+        let filtered = match self.filtered_recent_files.take() {
+            Some((id, filtered)) if id == filter_id => filtered,
+            _ => {
+                let file_filter = self.get_selected_file_filter();
+
+                recent_files
+                    .paths
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, file)| {
+                        file_filter.is_none_or(|f| f.matches(&file.path, &self.config))
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            }
+        };
+
+        let mut visible = false;
+
+        for file in filtered.iter().filter_map(|i| recent_files.paths.get(*i)) {
+            if !visible {
+                ui.add_space(spacing);
+                ui.label(&recent_files.heading);
+
+                visible = true;
+            }
+
+            let response = ui
+                .selectable_label(false, &file.display_name)
+                .on_hover_text(file.path.display().to_string());
+
+            if response.clicked() {
+                if let Some(parent) = file.path.parent() {
+                    self.load_directory(parent);
+                    self.select_item(&mut DirectoryEntry::from_path(
+                        &self.config,
+                        &file.path,
+                        &*self.config.file_system,
+                    ));
+                    self.scroll_to_selection = true;
+                }
+            }
+        }
+
+        self.recent_files = Some(recent_files);
+        self.filtered_recent_files = Some((filter_id, filtered));
+
+        visible
+    }
+
+    /// Adds the files picked by the user to the freedesktop list of recently used files,
+    /// if `FileDialogConfig::recent_files_application` is set.
+    /// The list is updated on a separate thread if `FileDialogConfig::load_via_thread`
+    /// is enabled. Errors are logged, as failing to update the list should not affect
+    /// the dialog.
+    #[cfg(feature = "recently_used")]
+    fn add_picked_to_recent_files(&self) {
+        let Some(application) = self.config.recent_files_application.clone() else {
+            return;
+        };
+
+        let Some(home_dir) = self
+            .user_directories
+            .as_ref()
+            .and_then(|d| d.home_dir())
+            .map(Path::to_path_buf)
+        else {
+            return;
+        };
+
+        let picked = match &self.state {
+            DialogState::Picked(path) => vec![path.clone()],
+            DialogState::PickedMultiple(paths) => paths.clone(),
+            _ => return,
+        };
+
+        let file_system = self.config.file_system.clone();
+        let mime_database = self.config.mime_database.clone();
+        let mime_detection = self.config.mime_detection;
+
+        let add = move || {
+            let files: Vec<(&Path, String)> = picked
+                .iter()
+                .filter(|p| !file_system.is_dir(p))
+                .map(|path| {
+                    let mime_type = mime_database.detect(path, &*file_system, mime_detection);
+                    let mime_type = mime_type
+                        .as_ref()
+                        .map_or("application/octet-stream", crate::MimeType::as_str);
+
+                    (path.as_path(), mime_type.to_string())
+                })
+                .collect();
+
+            let files: Vec<(&Path, &str)> = files
+                .iter()
+                .map(|(path, mime_type)| (*path, mime_type.as_str()))
+                .collect();
+
+            if let Err(err) = add_recent_files(&*file_system, &home_dir, &files, &application) {
+                log::warn!("Failed to add the picked files to the recently used files: {err}");
+            }
+        };
+
+        if self.config.load_via_thread {
+            thread::spawn(add);
+        } else {
+            add();
+        }
+    }
+
     /// Updates the list of pinned folders.
     ///
     /// Returns true if at least one directory item was included in the list and the
     /// heading is visible. If no item was listed, false is returned.
     fn ui_update_pinned_folders(&mut self, ui: &mut egui::Ui, spacing: f32) -> bool {
-        let mut visible = false;
+        let pinned_folders = self.storage.pinned_folders.clone();
+        let dragging_directory =
+            egui::DragAndDrop::has_payload_of_type::<DirectoryDragPayload>(ui.ctx());
+
+        // Show the section while a directory is dragged, so it can be pinned
+        // even if no folders are pinned yet.
+        if pinned_folders.is_empty() && !dragging_directory {
+            return false;
+        }
 
-        for (i, pinned) in self.storage.pinned_folders.clone().iter().enumerate() {
-            if i == 0 {
-                ui.add_space(spacing);
-                ui.label(self.config.labels.heading_pinned.as_str());
+        ui.add_space(spacing);
+        ui.label(self.config.labels.heading_pinned.as_str());
 
-                visible = true;
+        // The item dropped onto the list and the group it was dropped into.
+        let mut drop: Option<(PinnedFolderDrop, Option<String>)> = None;
+
+        let section = ui.scope(|ui| {
+            if pinned_folders.is_empty() {
+                let (_, payload) = ui.dnd_drop_zone::<DirectoryDragPayload, _>(
+                    egui::Frame::default().inner_margin(4.0),
+                    |ui| {
+                        ui.weak(&self.config.labels.drop_to_pin);
+                    },
+                );
+
+                if let Some(payload) = payload {
+                    let path = payload.0.clone();
+                    drop = Some((PinnedFolderDrop::Pin { path, to: 0 }, None));
+                }
+
+                return;
             }
 
-            if self.is_pinned_folder_being_renamed(pinned) {
-                self.ui_update_pinned_folder_rename(ui);
-                continue;
+            // Folders without a group are listed first, followed by the groups
+            // in the order they first appear.
+            let mut groups: Vec<Option<&str>> = vec![None];
+            for pinned in &pinned_folders {
+                if !groups.contains(&pinned.group.as_deref()) {
+                    groups.push(pinned.group.as_deref());
+                }
             }
 
-            let response = self.ui_update_left_panel_entry(
-                ui,
-                &format!("{}  {}", self.config.pinned_icon, &pinned.label),
-                pinned.path.as_path(),
-            );
+            for group in groups {
+                let group_id = self.window_id.with(("pinned_group", group));
+
+                let mut update_group = |ui: &mut egui::Ui| {
+                    for (i, pinned) in pinned_folders
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, p)| p.group.as_deref() == group)
+                    {
+                        if let Some(dropped) = self.ui_update_pinned_folder(ui, i, pinned) {
+                            drop = Some((dropped, pinned.group.clone()));
+                        }
+                    }
+                };
+
+                match group {
+                    None => update_group(ui),
+                    Some(name) => {
+                        egui::CollapsingHeader::new(name)
+                            .id_salt(group_id)
+                            .default_open(true)
+                            .show(ui, update_group);
+                    }
+                }
+            }
+        });
+
+        // Unpin a folder when it is dragged out of the section
+        if let Some(payload) = egui::DragAndDrop::payload::<PinnedFolderDragPayload>(ui.ctx()) {
+            let released_outside = ui.input(|i| {
+                i.pointer.any_released()
+                    && i.pointer
+                        .latest_pos()
+                        .is_none_or(|pos| !section.response.rect.contains(pos))
+            });
 
-            self.ui_update_pinned_folder_context_menu(&response, pinned);
+            if released_outside {
+                if let Some(pinned) = pinned_folders.get(payload.0) {
+                    self.unpin_path(&pinned.path);
+                }
+            }
         }
 
-        visible
+        match drop {
+            Some((PinnedFolderDrop::Move { from, to }, group)) => {
+                self.move_pinned_folder(from, to, group);
+            }
+            Some((PinnedFolderDrop::Pin { path, to }, group)) => {
+                self.pin_path_at(path, to, group);
+            }
+            None => {}
+        }
+
+        true
+    }
+
+    /// Updates a single pinned folder with the given index.
+    ///
+    /// Returns the item that was dropped onto this folder, if any.
+    fn ui_update_pinned_folder(
+        &mut self,
+        ui: &mut egui::Ui,
+        i: usize,
+        pinned: &PinnedFolder,
+    ) -> Option<PinnedFolderDrop> {
+        if self.is_pinned_folder_being_renamed(pinned) {
+            self.ui_update_pinned_folder_rename(ui);
+            return None;
+        }
+
+        let missing = self.missing_pinned_folders.contains(&pinned.path);
+
+        let mut text = egui::RichText::new(format!(
+            "{}  {}",
+            pinned.icon.as_deref().unwrap_or(&self.config.pinned_icon),
+            &pinned.label
+        ));
+
+        if missing {
+            text = text.color(ui.visuals().weak_text_color());
+        } else if let Some(color) = pinned.color {
+            text = text.color(color);
+        }
+
+        let mut response = self
+            .ui_update_left_panel_entry(ui, text, pinned.path.as_path())
+            .interact(egui::Sense::drag());
+
+        if missing {
+            response = response.on_hover_text(&self.config.labels.pinned_folder_missing);
+        }
+
+        response.dnd_set_drag_payload(PinnedFolderDragPayload(i));
+
+        self.ui_update_pinned_folder_context_menu(&response, pinned);
+
+        if let Some(to) =
+            Self::ui_update_drop_indicator::<PinnedFolderDragPayload>(ui, &response, i)
+        {
+            let payload = response.dnd_release_payload::<PinnedFolderDragPayload>()?;
+            return Some(PinnedFolderDrop::Move {
+                from: payload.0,
+                to,
+            });
+        }
+
+        let to = Self::ui_update_drop_indicator::<DirectoryDragPayload>(ui, &response, i)?;
+        let payload = response.dnd_release_payload::<DirectoryDragPayload>()?;
+
+        Some(PinnedFolderDrop::Pin {
+            path: payload.0.clone(),
+            to,
+        })
     }
 
     fn ui_update_pinned_folder_rename(&mut self, ui: &mut egui::Ui) {
@@ -1869,63 +3117,263 @@ impl FileDialog {
                 self.begin_rename_pinned_folder(pinned.clone());
                 ui.close();
             }
+
+            let move_to_group = self.config.labels.move_pinned_folder_to_group.clone();
+            ui.menu_button(move_to_group, |ui| {
+                self.ui_update_pinned_folder_group_menu(ui, pinned);
+            });
+
+            let appearance = self.config.labels.pinned_folder_appearance.clone();
+            ui.menu_button(appearance, |ui| {
+                self.ui_update_pinned_folder_appearance_menu(ui, pinned);
+            });
+
+            if !self.missing_pinned_folders.is_empty()
+                && ui
+                    .button(&self.config.labels.remove_missing_pinned_folders)
+                    .clicked()
+            {
+                self.remove_missing_pinned_folders();
+                ui.close();
+            }
         });
     }
 
-    /// Updates the list of user directories (Places).
-    ///
-    /// Returns true if at least one directory was included in the list and the
-    /// heading is visible. If no directory was listed, false is returned.
-    fn ui_update_user_directories(&mut self, ui: &mut egui::Ui, spacing: f32) -> bool {
-        // Take temporary ownership of the user directories and configuration.
-        // This is done so that we don't have to clone the user directories and
-        // configured display names.
-        let user_directories = std::mem::take(&mut self.user_directories);
-        let labels = std::mem::take(&mut self.config.labels);
+    /// Updates the menu to move a pinned folder into one of the existing groups,
+    /// out of its group, or into a new group entered by the user.
+    fn ui_update_pinned_folder_group_menu(&mut self, ui: &mut egui::Ui, pinned: &PinnedFolder) {
+        let mut groups: Vec<String> = Vec::new();
+        for group in self
+            .storage
+            .pinned_folders
+            .iter()
+            .filter_map(|p| p.group.as_ref())
+        {
+            if !groups.contains(group) {
+                groups.push(group.clone());
+            }
+        }
 
-        let mut visible = false;
+        let mut move_to: Option<Option<String>> = (pinned.group.is_some()
+            && ui
+                .button(&self.config.labels.pinned_folder_no_group)
+                .clicked())
+        .then_some(None);
 
-        if let Some(dirs) = &user_directories {
-            ui.add_space(spacing);
-            ui.label(labels.heading_places.as_str());
+        for group in groups {
+            let current = pinned.group.as_ref() == Some(&group);
 
-            if let Some(path) = dirs.home_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.home_dir, path);
-            }
-            if let Some(path) = dirs.desktop_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.desktop_dir, path);
-            }
-            if let Some(path) = dirs.document_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.documents_dir, path);
-            }
-            if let Some(path) = dirs.download_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.downloads_dir, path);
-            }
-            if let Some(path) = dirs.audio_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.audio_dir, path);
-            }
-            if let Some(path) = dirs.picture_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.pictures_dir, path);
-            }
-            if let Some(path) = dirs.video_dir() {
-                self.ui_update_left_panel_entry(ui, &labels.videos_dir, path);
+            if ui
+                .add_enabled(!current, egui::Button::new(&group))
+                .clicked()
+            {
+                move_to = Some(Some(group));
             }
+        }
+
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.new_pinned_folder_group)
+                .hint_text(&self.config.labels.new_pinned_folder_group),
+        );
 
-            visible = true;
+        let new_group = self.new_pinned_folder_group.trim();
+        if response.lost_focus()
+            && ui.input(|i| i.key_pressed(egui::Key::Enter))
+            && !new_group.is_empty()
+        {
+            move_to = Some(Some(new_group.to_string()));
+            self.new_pinned_folder_group.clear();
         }
 
-        self.user_directories = user_directories;
-        self.config.labels = labels;
+        if let Some(group) = move_to {
+            if let Some(i) = self
+                .storage
+                .pinned_folders
+                .iter()
+                .position(|p| p.path == pinned.path)
+            {
+                self.move_pinned_folder(i, i, group);
+            }
 
-        visible
+            ui.close();
+        }
     }
 
-    /// Updates the list of devices like system disks.
-    ///
-    /// Returns true if at least one device was included in the list and the
-    /// heading is visible. If no device was listed, false is returned.
-    fn ui_update_devices(&mut self, ui: &mut egui::Ui, spacing: f32, disks: &Disks) -> bool {
-        let mut visible = false;
+    /// Updates the menu to change the icon and color of a pinned folder.
+    fn ui_update_pinned_folder_appearance_menu(
+        &mut self,
+        ui: &mut egui::Ui,
+        pinned: &PinnedFolder,
+    ) {
+        let mut icon = pinned.icon.clone().unwrap_or_default();
+        let mut color = pinned.color.unwrap_or_else(|| ui.visuals().text_color());
+
+        let icon_changed = ui
+            .add(
+                egui::TextEdit::singleline(&mut icon)
+                    .hint_text(&self.config.pinned_icon)
+                    .desired_width(60.0),
+            )
+            .changed();
+
+        let color_changed = egui::color_picker::color_picker_color32(
+            ui,
+            &mut color,
+            egui::color_picker::Alpha::Opaque,
+        );
+
+        let reset = ui
+            .add_enabled(
+                pinned.icon.is_some() || pinned.color.is_some(),
+                egui::Button::new(&self.config.labels.reset_pinned_folder_appearance),
+            )
+            .clicked();
+
+        let Some(target) = self
+            .storage
+            .pinned_folders
+            .iter_mut()
+            .find(|p| p.path == pinned.path)
+        else {
+            return;
+        };
+
+        if icon_changed {
+            target.icon = (!icon.trim().is_empty()).then(|| icon.trim().to_string());
+        }
+
+        if color_changed {
+            target.color = Some(color);
+        }
+
+        if reset {
+            target.icon = None;
+            target.color = None;
+            ui.close();
+        }
+    }
+
+    /// Updates the list of user directories (Places).
+    ///
+    /// Returns true if at least one directory was included in the list and the
+    /// heading is visible. If no directory was listed, false is returned.
+    fn ui_update_user_directories(&mut self, ui: &mut egui::Ui, spacing: f32) -> bool {
+        // Take temporary ownership of the user directories.
+        // This is done so that we don't have to clone the user directories.
+        let user_directories = std::mem::take(&mut self.user_directories);
+        let places = self.get_places();
+
+        // The index of the place the user dragged and the index it was dropped at.
+        let mut move_place: Option<(usize, usize)> = None;
+        let mut visible = false;
+
+        if let Some(dirs) = &user_directories {
+            for (i, place) in places.iter().enumerate() {
+                let Some(path) = dirs.get(place.directory) else {
+                    continue;
+                };
+
+                if !visible {
+                    ui.add_space(spacing);
+                    ui.label(self.config.labels.heading_places.as_str());
+
+                    visible = true;
+                }
+
+                let display_name = place.display_name.as_deref().map_or_else(
+                    || {
+                        self.config
+                            .labels
+                            .user_directory(place.directory)
+                            .to_string()
+                    },
+                    str::to_string,
+                );
+
+                let response = self
+                    .ui_update_left_panel_entry(ui, &display_name, path)
+                    .interact(egui::Sense::drag());
+
+                response.dnd_set_drag_payload(PlaceDragPayload(i));
+
+                if let Some(target) =
+                    Self::ui_update_drop_indicator::<PlaceDragPayload>(ui, &response, i)
+                {
+                    if let Some(payload) = response.dnd_release_payload::<PlaceDragPayload>() {
+                        move_place = Some((payload.0, target));
+                    }
+                }
+            }
+        }
+
+        self.user_directories = user_directories;
+
+        if let Some((from, to)) = move_place {
+            self.move_place(places, from, to);
+        }
+
+        visible
+    }
+
+    /// Returns the places listed in the Places section, in the order the user arranged them.
+    fn get_places(&self) -> Vec<Place> {
+        let mut places = self.config.places.clone();
+
+        places.sort_by_key(|place| {
+            self.storage
+                .place_order
+                .iter()
+                .position(|d| *d == place.directory)
+                .unwrap_or(usize::MAX)
+        });
+
+        places
+    }
+
+    /// Moves the place at index `from` to index `to` and stores the new order.
+    fn move_place(&mut self, mut places: Vec<Place>, from: usize, to: usize) {
+        if from >= places.len() {
+            return;
+        }
+
+        let place = places.remove(from);
+        let to = if from < to { to - 1 } else { to };
+        places.insert(to.min(places.len()), place);
+
+        self.storage.place_order = places.iter().map(|p| p.directory).collect();
+    }
+
+    /// Paints a line indicating where an item dragged over the given row is inserted.
+    ///
+    /// Returns the index the dragged item is inserted at, before or after the row
+    /// with the given index depending on which half of the row the pointer is in.
+    /// None if no payload of the given type is dragged over the row.
+    fn ui_update_drop_indicator<P: Send + Sync + 'static>(
+        ui: &egui::Ui,
+        row: &egui::Response,
+        i: usize,
+    ) -> Option<usize> {
+        row.dnd_hover_payload::<P>()?;
+
+        let pointer = ui.ctx().pointer_interact_pos()?;
+        let (target, y) = if pointer.y < row.rect.center().y {
+            (i, row.rect.top())
+        } else {
+            (i + 1, row.rect.bottom())
+        };
+
+        ui.painter()
+            .hline(row.rect.x_range(), y, ui.visuals().selection.stroke);
+
+        Some(target)
+    }
+
+    /// Updates the list of devices like system disks.
+    ///
+    /// Returns true if at least one device was included in the list and the
+    /// heading is visible. If no device was listed, false is returned.
+    fn ui_update_devices(&mut self, ui: &mut egui::Ui, spacing: f32, disks: &Disks) -> bool {
+        let mut visible = false;
 
         for (i, disk) in disks.iter().filter(|x| !x.is_removable()).enumerate() {
             if i == 0 {
@@ -1979,7 +3427,77 @@ impl FileDialog {
             format!("{}  {}", self.config.device_icon, device.display_name())
         };
 
-        self.ui_update_left_panel_entry(ui, &label, device.mount_point());
+        let response = self.ui_update_left_panel_entry(ui, &label, device.mount_point());
+
+        if let Some(usage) = device.usage() {
+            Self::ui_paint_device_usage_bar(ui, &response, usage);
+        }
+
+        if let Some(tooltip) = self.get_device_tooltip(device) {
+            response.on_hover_text(tooltip);
+        }
+    }
+
+    /// Paints a thin bar at the bottom of a device entry showing how much of the
+    /// device's space is used.
+    fn ui_paint_device_usage_bar(ui: &egui::Ui, response: &egui::Response, usage: f32) {
+        const HEIGHT: f32 = 2.0;
+        /// Usage above which the bar is displayed in the warning color.
+        const WARN_USAGE: f32 = 0.9;
+
+        let padding = ui.spacing().button_padding.x;
+        let track = egui::Rect::from_min_max(
+            egui::pos2(
+                response.rect.left() + padding,
+                response.rect.bottom() - HEIGHT,
+            ),
+            egui::pos2(response.rect.right() - padding, response.rect.bottom()),
+        );
+
+        let mut fill = track;
+        fill.set_width(track.width() * usage.clamp(0.0, 1.0));
+
+        let color = if usage > WARN_USAGE {
+            ui.visuals().warn_fg_color
+        } else {
+            ui.visuals().selection.bg_fill
+        };
+
+        ui.painter()
+            .rect_filled(track, 1.0, ui.visuals().extreme_bg_color);
+        ui.painter().rect_filled(fill, 1.0, color);
+    }
+
+    /// Returns the tooltip of a device entry showing the available and total space and
+    /// the file system of the device.
+    fn get_device_tooltip(&self, device: &Disk) -> Option<String> {
+        let usage = match (device.available_space(), device.total_space()) {
+            (Some(available), Some(total)) => Some(self.config.labels.format(
+                &self.config.labels.device_usage,
+                &[
+                    ("available", &format_bytes(available)),
+                    ("total", &format_bytes(total)),
+                ],
+            )),
+            _ => None,
+        };
+
+        match (usage, device.file_system()) {
+            (Some(usage), Some(file_system)) => Some(format!("{usage}\n{file_system}")),
+            (Some(usage), None) => Some(usage),
+            (None, file_system) => file_system.map(str::to_string),
+        }
+    }
+
+    /// Returns the device containing the current directory.
+    /// If multiple mount points contain the directory, the most specific one is returned.
+    fn get_current_device(&self) -> Option<&Disk> {
+        let directory = self.current_directory()?;
+
+        self.system_disks
+            .iter()
+            .filter(|disk| directory.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().components().count())
     }
 
     /// Updates the bottom panel showing the selected item and main action buttons.
@@ -1992,6 +3510,9 @@ impl FileDialog {
             DialogMode::PickDirectory | DialogMode::PickFile | DialogMode::PickMultiple => {
                 Self::calc_text_width(ui, &self.config.labels.open_button)
             }
+            DialogMode::PickFileOrDirectory => {
+                Self::calc_text_width(ui, &self.config.labels.select_button)
+            }
             DialogMode::SaveFile => Self::calc_text_width(ui, &self.config.labels.save_button),
         };
 
@@ -2005,6 +3526,10 @@ impl FileDialog {
         // The size of the action buttons "cancel" and "open"/"save"
         let button_size: egui::Vec2 = egui::Vec2::new(btn_width, BUTTON_HEIGHT);
 
+        if self.mode == DialogMode::PickMultiple && !self.multi_selection.is_empty() {
+            self.ui_update_multi_selection_basket(ui);
+        }
+
         self.ui_update_selection_preview(ui, button_size);
 
         if self.mode == DialogMode::SaveFile && self.config.save_extensions.is_empty() {
@@ -2014,23 +3539,102 @@ impl FileDialog {
         self.ui_update_action_buttons(ui, button_size);
     }
 
+    /// Updates the collapsible list of every item of the multi selection,
+    /// including the items selected in other directories.
+    /// The items can be reordered using drag and drop and removed from the selection.
+    fn ui_update_multi_selection_basket(&mut self, ui: &mut egui::Ui) {
+        const MAX_HEIGHT: f32 = 100.0;
+
+        // The item the user wants to remove from the selection.
+        let mut remove_item: Option<DirectoryEntry> = None;
+        // The index of the item the user dragged and the index it was dropped at.
+        let mut move_item: Option<(usize, usize)> = None;
+
+        egui::CollapsingHeader::new(self.config.labels.format(
+            &self.config.labels.selection_basket,
+            &[("count", &self.multi_selection.len().to_string())],
+        ))
+        .id_salt(self.window_id.with("selection_basket"))
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::containers::ScrollArea::vertical()
+                .max_height(MAX_HEIGHT)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for (i, item) in self.multi_selection.iter().enumerate() {
+                        let row = ui
+                            .horizontal(|ui| {
+                                ui.dnd_drag_source(
+                                    self.window_id.with(("selection_basket_item", i)),
+                                    MultiSelectionDragPayload(i),
+                                    |ui| ui.add(egui::Label::new("☰").selectable(false)),
+                                );
+
+                                ui.label(format!(
+                                    "{}. {} {}",
+                                    i + 1,
+                                    item.icon(),
+                                    item.file_name()
+                                ))
+                                .on_hover_text(item.as_path().display().to_string());
+
+                                if ui.small_button("✖").clicked() {
+                                    remove_item = Some(item.clone());
+                                }
+                            })
+                            .response;
+
+                        if let Some(target) =
+                            Self::ui_update_drop_indicator::<MultiSelectionDragPayload>(ui, &row, i)
+                        {
+                            if let Some(payload) =
+                                row.dnd_release_payload::<MultiSelectionDragPayload>()
+                            {
+                                move_item = Some((payload.0, target));
+                            }
+                        }
+                    }
+                });
+        });
+
+        if let Some((from, to)) = move_item {
+            if from < self.multi_selection.len() {
+                let item = self.multi_selection.remove(from);
+                let to = if from < to { to - 1 } else { to };
+                self.multi_selection
+                    .insert(to.min(self.multi_selection.len()), item);
+            }
+        }
+
+        if let Some(item) = remove_item {
+            self.deselect_multi_selection_item(&item);
+        }
+    }
+
     /// Updates the selection preview like "Selected directory: X"
     fn ui_update_selection_preview(&mut self, ui: &mut egui::Ui, button_size: egui::Vec2) {
         const SELECTION_PREVIEW_MIN_WIDTH: f32 = 50.0;
         let item_spacing = ui.style().spacing.item_spacing;
 
         let render_filter_selection = (!self.config.file_filters.is_empty()
-            && (self.mode == DialogMode::PickFile || self.mode == DialogMode::PickMultiple))
+            && matches!(
+                self.mode,
+                DialogMode::PickFile | DialogMode::PickFileOrDirectory | DialogMode::PickMultiple
+            ))
             || (!self.config.save_extensions.is_empty() && self.mode == DialogMode::SaveFile);
 
         let filter_selection_width = button_size.x.mul_add(2.0, item_spacing.x);
         let mut filter_selection_separate_line = false;
 
-        ui.horizontal(|ui| {
+        ui.with_layout(self.row_layout(egui::Align::Center, false), |ui| {
             match &self.mode {
                 DialogMode::PickDirectory => ui.label(&self.config.labels.selected_directory),
                 DialogMode::PickFile => ui.label(&self.config.labels.selected_file),
-                DialogMode::PickMultiple => ui.label(&self.config.labels.selected_items),
+                DialogMode::PickFileOrDirectory => ui.label(&self.config.labels.selected_item),
+                DialogMode::PickMultiple => ui.label(self.config.labels.format(
+                    &self.config.labels.selected_items,
+                    &[("count", &self.multi_selection.len().to_string())],
+                )),
                 DialogMode::SaveFile => ui.label(&self.config.labels.file_name),
             };
 
@@ -2047,10 +3651,19 @@ impl FileDialog {
             }
 
             match &self.mode {
-                DialogMode::PickDirectory | DialogMode::PickFile | DialogMode::PickMultiple => {
+                DialogMode::PickDirectory
+                | DialogMode::PickFile
+                | DialogMode::PickFileOrDirectory
+                | DialogMode::PickMultiple => {
                     use egui::containers::scroll_area::ScrollBarVisibility;
 
-                    let text = self.get_selection_preview_text();
+                    let text = self.get_selection_preview_text(ui.ctx());
+                    let error = self.get_multi_selection_error();
+                    let tooltip = if self.mode == DialogMode::PickMultiple {
+                        self.get_multi_selection_tooltip()
+                    } else {
+                        String::new()
+                    };
 
                     egui::containers::ScrollArea::horizontal()
                         .auto_shrink([false, false])
@@ -2058,7 +3671,19 @@ impl FileDialog {
                         .stick_to_right(true)
                         .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
                         .show(ui, |ui| {
-                            ui.colored_label(ui.style().visuals.selection.bg_fill, text);
+                            let layout = egui::Layout::left_to_right(egui::Align::Center);
+                            ui.with_layout(layout, |ui| {
+                                if let Some(error) = error {
+                                    ui.colored_label(ui.visuals().error_fg_color, error);
+                                } else {
+                                    let re = ui
+                                        .colored_label(ui.style().visuals.selection.bg_fill, text);
+
+                                    if !tooltip.is_empty() {
+                                        re.on_hover_text(tooltip);
+                                    }
+                                }
+                            });
                         });
                 }
                 DialogMode::SaveFile => {
@@ -2097,7 +3722,7 @@ impl FileDialog {
         });
 
         if filter_selection_separate_line && render_filter_selection {
-            ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+            ui.with_layout(self.row_layout(egui::Align::Min, true), |ui| {
                 if self.mode == DialogMode::SaveFile {
                     self.ui_update_save_extension_selection(ui, filter_selection_width);
                 } else {
@@ -2122,31 +3747,30 @@ impl FileDialog {
         }
     }
 
-    fn get_selection_preview_text(&self) -> String {
+    /// Returns the message explaining why the multi selection is invalid.
+    /// Nothing is returned if the user did not select anything yet and
+    /// selecting a single item would be enough.
+    fn get_multi_selection_error(&self) -> Option<String> {
+        if self.mode != DialogMode::PickMultiple
+            || (self.selected_entries().next().is_none()
+                && self.config.selection_constraints.min_items <= 1)
+        {
+            return None;
+        }
+
+        self.validate_multi_selection()
+    }
+
+    fn get_selection_preview_text(&self, ctx: &egui::Context) -> String {
         if self.is_selection_valid() {
             match &self.mode {
-                DialogMode::PickDirectory | DialogMode::PickFile => self
+                DialogMode::PickDirectory
+                | DialogMode::PickFile
+                | DialogMode::PickFileOrDirectory => self
                     .selected_item
                     .as_ref()
                     .map_or_else(String::new, |item| item.file_name().to_string()),
-                DialogMode::PickMultiple => {
-                    let mut result = String::new();
-
-                    for (i, item) in self
-                        .get_dir_content_filtered_iter()
-                        .filter(|p| p.selected)
-                        .enumerate()
-                    {
-                        if i == 0 {
-                            result += item.file_name();
-                            continue;
-                        }
-
-                        result += format!(", {}", item.file_name()).as_str();
-                    }
-
-                    result
-                }
+                DialogMode::PickMultiple => self.get_multi_selection_summary(ctx),
                 DialogMode::SaveFile => String::new(),
             }
         } else {
@@ -2157,7 +3781,7 @@ impl FileDialog {
     fn ui_update_file_filter_selection(&mut self, ui: &mut egui::Ui, width: f32) {
         let selected_filter = self.get_selected_file_filter();
         let selected_text = match selected_filter {
-            Some(f) => &f.name,
+            Some(f) => &f.to_string(),
             None => &self.config.labels.file_filter_all_files,
         };
 
@@ -2173,7 +3797,7 @@ impl FileDialog {
                 for filter in &self.config.file_filters {
                     let selected = selected_filter.is_some_and(|f| f.id == filter.id);
 
-                    if ui.selectable_label(selected, &filter.name).clicked() {
+                    if ui.selectable_label(selected, filter.to_string()).clicked() {
                         select_filter = Some(Some(filter.clone()));
                     }
                 }
@@ -2228,13 +3852,76 @@ impl FileDialog {
         }
     }
 
+    /// Returns a summary of the multi selection, like "12 files, 3 folders, 1.4 GB total".
+    /// The size of the selected directories is calculated in the background and
+    /// marked with "…" as long as the calculation is running.
+    /// If directory sizes are not calculated, only the size of the selected files is shown.
+    fn get_multi_selection_summary(&self, ctx: &egui::Context) -> String {
+        let mut files: usize = 0;
+        let mut folders: usize = 0;
+        let mut bytes: u64 = 0;
+        let mut complete = true;
+
+        for item in self.selected_entries() {
+            if item.is_dir() {
+                folders += 1;
+
+                if let Some(size) = self.directory_size(ctx, item.as_path()) {
+                    bytes += size.bytes;
+                    complete &= size.complete;
+                }
+            } else {
+                files += 1;
+                bytes += item.metadata().size.unwrap_or_default();
+            }
+        }
+
+        let mut size = format_bytes(bytes);
+        if !complete {
+            size.push('…');
+        }
+
+        let label = if self.config.calculate_directory_sizes {
+            &self.config.labels.selection_summary
+        } else {
+            &self.config.labels.selection_summary_without_folder_sizes
+        };
+
+        self.config.labels.format(
+            label,
+            &[
+                ("files", &files.to_string()),
+                ("folders", &folders.to_string()),
+                ("size", &size),
+            ],
+        )
+    }
+
+    /// Returns the text of the tooltip listing the items of the multi selection.
+    fn get_multi_selection_tooltip(&self) -> String {
+        const MAX_ITEMS: usize = 30;
+
+        let mut lines: Vec<String> = self
+            .selected_entries()
+            .take(MAX_ITEMS)
+            .map(|item| format!("{} {}", item.icon(), item.as_path().display()))
+            .collect();
+
+        if self.multi_selection.len() > MAX_ITEMS {
+            lines.push("…".to_string());
+        }
+
+        lines.join("\n")
+    }
+
     /// Updates the action buttons like save, open and cancel
     fn ui_update_action_buttons(&mut self, ui: &mut egui::Ui, button_size: egui::Vec2) {
-        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+        ui.with_layout(self.row_layout(egui::Align::Min, true), |ui| {
             let label = match &self.mode {
                 DialogMode::PickDirectory | DialogMode::PickFile | DialogMode::PickMultiple => {
                     self.config.labels.open_button.as_str()
                 }
+                DialogMode::PickFileOrDirectory => self.config.labels.select_button.as_str(),
                 DialogMode::SaveFile => self.config.labels.save_button.as_str(),
             };
 
@@ -2257,6 +3944,15 @@ impl FileDialog {
             {
                 self.cancel();
             }
+
+            if self.mode == DialogMode::SaveFile {
+                if let Some(available) = self.get_current_device().and_then(Disk::available_space) {
+                    ui.weak(self.config.labels.format(
+                        &self.config.labels.free_space,
+                        &[("size", &format_bytes(available))],
+                    ));
+                }
+            }
         });
     }
 
@@ -2331,21 +4027,26 @@ impl FileDialog {
         // If we should return after updating the directory entries.
         let mut should_return = false;
 
+        // The number of items that are currently part of the multi selection.
+        let selection_count = self.count_multi_selection(&data);
+
         ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
             let scroll_area = egui::containers::ScrollArea::vertical().auto_shrink([false, false]);
 
             if self.search_value.is_empty()
+                && !data.quick_filter().is_active()
                 && !self.create_directory_dialog.is_open()
                 && !self.scroll_to_selection
             {
-                // Only update visible items when the search value is empty,
-                // the create directory dialog is closed and we are currently not scrolling
-                // to the current item.
+                // Only update visible items when the search value is empty, no quick
+                // filter is set, the create directory dialog is closed and we are
+                // currently not scrolling to the current item.
                 scroll_area.show_rows(ui, ui.spacing().interact_size.y, data.len(), |ui, range| {
                     for item in data.iter_range_mut(range) {
                         if self.ui_update_central_panel_entry(
                             ui,
                             item,
+                            selection_count,
                             &mut reset_multi_selection,
                             &mut batch_select_item_b,
                         ) {
@@ -2364,6 +4065,7 @@ impl FileDialog {
                         if self.ui_update_central_panel_entry(
                             ui,
                             item,
+                            selection_count,
                             &mut reset_multi_selection,
                             &mut batch_select_item_b,
                         ) {
@@ -2406,15 +4108,71 @@ impl FileDialog {
         self.scroll_to_selection = false;
     }
 
+    /// Returns the size displayed next to an entry in the central panel.
+    /// Returns None if the size column is disabled.
+    fn get_central_panel_entry_size(
+        &self,
+        ctx: &egui::Context,
+        item: &DirectoryEntry,
+    ) -> Option<String> {
+        if !self.config.calculate_directory_sizes {
+            return None;
+        }
+
+        if item.is_dir() {
+            return self
+                .directory_size(ctx, item.as_path())
+                .map(|size| size.to_string());
+        }
+
+        Some(item.metadata().size.map(format_bytes).unwrap_or_default())
+    }
+
+    /// Updates the selectable label of an entry in the central panel.
+    /// If a size is given, it is displayed in a separate column at the end of the row,
+    /// so that it never overlaps the name of the entry.
+    fn ui_update_central_panel_entry_label(
+        &self,
+        ui: &mut egui::Ui,
+        selected: bool,
+        text: String,
+        size: Option<(String, f32)>,
+    ) -> egui::Response {
+        let Some((size_text, size_width)) = size else {
+            return ui.selectable_label(selected, text);
+        };
+
+        let row_size = egui::vec2(ui.available_width(), ui.spacing().interact_size.y);
+
+        ui.allocate_ui_with_layout(row_size, self.row_layout(egui::Align::Center, true), |ui| {
+            ui.add_sized(
+                egui::vec2(size_width, row_size.y),
+                egui::Label::new(egui::RichText::new(size_text).weak())
+                    .selectable(false)
+                    .wrap_mode(egui::TextWrapMode::Extend),
+            );
+
+            ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
+                ui.selectable_label(selected, text)
+            })
+            .inner
+        })
+        .inner
+    }
+
     /// Updates a single directory content entry.
     /// TODO: Refactor
     fn ui_update_central_panel_entry(
         &mut self,
         ui: &mut egui::Ui,
         item: &mut DirectoryEntry,
+        selection_count: usize,
         reset_multi_selection: &mut bool,
         batch_select_item_b: &mut Option<DirectoryEntry>,
     ) -> bool {
+        /// Width reserved for the size of the entry on the right side.
+        const SIZE_COLUMN_WIDTH: f32 = 70.0;
+
         let file_name = item.file_name();
         let primary_selected = self.is_primary_selected(item);
         let pinned = self.is_pinned(item.as_path());
@@ -2427,8 +4185,11 @@ impl FileDialog {
 
         let icons_width = Self::calc_text_width(ui, &icons);
 
+        let size_text = self.get_central_panel_entry_size(ui.ctx(), item);
+        let size_width = size_text.as_ref().map_or(0.0, |_| SIZE_COLUMN_WIDTH);
+
         // Calc available width for the file name and include a small margin
-        let available_width = ui.available_width() - icons_width - 15.0;
+        let available_width = ui.available_width() - icons_width - size_width - 15.0;
 
         let truncate = self.config.truncate_filenames
             && available_width < Self::calc_text_width(ui, file_name);
@@ -2439,8 +4200,12 @@ impl FileDialog {
             file_name.to_owned()
         };
 
-        let mut re =
-            ui.selectable_label(primary_selected || item.selected, format!("{icons}{text}"));
+        let mut re = self.ui_update_central_panel_entry_label(
+            ui,
+            primary_selected || item.selected,
+            format!("{icons}{text}"),
+            size_text.map(|size_text| (size_text, size_width)),
+        );
 
         if truncate {
             re = re.on_hover_text(file_name);
@@ -2452,6 +4217,15 @@ impl FileDialog {
             if re.context_menu_opened() {
                 self.select_item(item);
             }
+
+            // Directories can be dragged onto the pinned folders in the left panel
+            if self.config.show_pinned_folders {
+                re = re.interact(egui::Sense::drag());
+
+                if re.drag_started() {
+                    re.dnd_set_drag_payload(DirectoryDragPayload(item.to_path_buf()));
+                }
+            }
         }
 
         if primary_selected && self.scroll_to_selection {
@@ -2459,10 +4233,13 @@ impl FileDialog {
             self.scroll_to_selection = false;
         }
 
-        // The user wants to select the item as the primary selected item
+        // The user wants to select the item as the primary selected item.
+        // Entries of a kind that cannot be selected in multi selection mode are ignored.
         if re.clicked()
             && !ui.input(|i| i.modifiers.command)
             && !ui.input(|i| i.modifiers.shift_only())
+            && (self.mode != DialogMode::PickMultiple
+                || self.config.selection_constraints.allows(item))
         {
             self.select_item(item);
 
@@ -2478,18 +4255,18 @@ impl FileDialog {
             && re.clicked()
             && ui.input(|i| i.modifiers.command)
         {
+            let constraints = &self.config.selection_constraints;
+
             if primary_selected {
                 // If the clicked item is the primary selected item,
                 // deselect it and remove it from the multi selection
                 item.selected = false;
                 self.selected_item = None;
-            } else {
-                item.selected = !item.selected;
-
+            } else if item.selected {
+                item.selected = false;
+            } else if constraints.allows(item) && constraints.allows_more(selection_count) {
                 // If the item was selected, make it the primary selected item
-                if item.selected {
-                    self.select_item(item);
-                }
+                self.select_item(item);
             }
         }
 
@@ -2499,14 +4276,8 @@ impl FileDialog {
             && re.clicked()
             && ui.input(|i| i.modifiers.shift_only())
         {
-            if let Some(selected_item) = self.selected_item.clone() {
-                // We perform a batch selection from the item that was
-                // primarily selected before the user clicked on this item.
+            if let Some(selected_item) = self.shift_select_item(item, selection_count) {
                 *batch_select_item_b = Some(selected_item);
-
-                // And now make this item the primary selected item
-                item.selected = true;
-                self.select_item(item);
             }
         }
 
@@ -2535,6 +4306,34 @@ impl FileDialog {
 
     /// Selects every item inside the `directory_content` between `item_a` and `item_b`,
     /// excluding both given items.
+    /// Handles a shift click on the given item in multi selection mode.
+    /// The item becomes the primary selected item, if its kind can be selected.
+    ///
+    /// Returns the item that was primarily selected before, from which the items up
+    /// to the given item are batch selected. None if no item was selected before.
+    fn shift_select_item(
+        &mut self,
+        item: &mut DirectoryEntry,
+        selection_count: usize,
+    ) -> Option<DirectoryEntry> {
+        let selected_item = self.selected_item.clone()?;
+        let constraints = &self.config.selection_constraints;
+
+        if constraints.allows(item) {
+            let was_selected = item.selected;
+            let allows_more = constraints.allows_more(selection_count);
+
+            // Make this item the primary selected item
+            self.select_item(item);
+
+            if !was_selected && !allows_more {
+                item.selected = false;
+            }
+        }
+
+        Some(selected_item)
+    }
+
     fn batch_select_between(
         &self,
         directory_content: &mut DirectoryContent,
@@ -2557,28 +4356,55 @@ impl FileDialog {
                     return;
                 }
 
-                // Get the min and max of both positions.
-                // We will iterate from min to max.
-                let mut min = pos_a;
-                let mut max = pos_b;
-
-                if min > max {
-                    min = pos_b;
-                    max = pos_a;
-                }
+                let constraints = &self.config.selection_constraints;
+                let mut count = self.count_multi_selection(directory_content);
 
-                for item in directory_content
+                let mut items: Vec<&mut DirectoryEntry> = directory_content
                     .filtered_iter_mut(&self.search_value)
-                    .enumerate()
-                    .filter(|(i, _)| i > &min && i < &max)
-                    .map(|(_, p)| p)
-                {
-                    item.selected = true;
+                    .collect();
+
+                // Iterate from item a towards item b, so that the items closest to
+                // item a are selected first if the number of items is limited.
+                let between: Vec<usize> = if pos_a < pos_b {
+                    (pos_a + 1..pos_b).collect()
+                } else {
+                    (pos_b + 1..pos_a).rev().collect()
+                };
+
+                for i in between {
+                    if !constraints.allows_more(count) {
+                        break;
+                    }
+
+                    if let Some(item) = items.get_mut(i) {
+                        if !item.selected && constraints.allows(item) {
+                            item.selected = true;
+                            count += 1;
+                        }
+                    }
                 }
             }
         }
     }
 
+    /// Returns if the dialog is laid out from right to left.
+    fn is_right_to_left(&self) -> bool {
+        self.config
+            .right_to_left
+            .unwrap_or_else(|| self.config.labels.is_right_to_left())
+    }
+
+    /// Returns the layout of a row in reading direction, which is mirrored in
+    /// right-to-left mode. If `align_end` is true, the row starts at the end instead,
+    /// like the action buttons in the bottom right.
+    fn row_layout(&self, valign: egui::Align, align_end: bool) -> egui::Layout {
+        if self.is_right_to_left() == align_end {
+            egui::Layout::left_to_right(valign)
+        } else {
+            egui::Layout::right_to_left(valign)
+        }
+    }
+
     /// Helper function to add a sized button that can be enabled or disabled
     fn ui_button_sized(
         &self,
@@ -2772,6 +4598,7 @@ impl FileDialog {
         }
 
         if FileDialogKeyBindings::any_pressed(ctx, &keybindings.reload, true) {
+            self.directory_sizes.clear();
             self.refresh();
         }
 
@@ -2813,8 +4640,18 @@ impl FileDialog {
         if FileDialogKeyBindings::any_pressed(ctx, &keybindings.select_all, true)
             && self.mode == DialogMode::PickMultiple
         {
+            let constraints = &self.config.selection_constraints;
+            let mut count = self.count_multi_selection(&self.directory_content);
+
             for item in self.directory_content.filtered_iter_mut(&self.search_value) {
-                item.selected = true;
+                if !constraints.allows_more(count) {
+                    break;
+                }
+
+                if !item.selected && constraints.allows(item) {
+                    item.selected = true;
+                    count += 1;
+                }
             }
         }
 
@@ -2880,7 +4717,6 @@ impl FileDialog {
             self.close_path_edit();
         } else if !self.any_focused_last_frame {
             self.cancel();
-            return;
         }
     }
 
@@ -2925,30 +4761,149 @@ impl FileDialog {
 
 /// Implementation
 impl FileDialog {
-    /// Get the file filter the user currently selected.
-    fn get_selected_file_filter(&self) -> Option<&FileFilter> {
-        self.selected_file_filter
-            .and_then(|id| self.config.file_filters.iter().find(|p| p.id == id))
-    }
+    /// Synchronizes the multi selection with the selection state of the current
+    /// directory content.
+    ///
+    /// After a directory has been loaded, the items that are already part of the multi
+    /// selection are marked as selected. Afterwards, items the user selected are
+    /// added to the multi selection and items the user deselected are removed from it.
+    fn sync_multi_selection(&mut self) {
+        if self.mode != DialogMode::PickMultiple
+            || !matches!(
+                self.directory_content.state(),
+                DirectoryContentState::Success | DirectoryContentState::Finished
+            )
+        {
+            return;
+        }
 
-    /// Sets the default file filter to use.
-    fn set_default_file_filter(&mut self) {
-        if let Some(name) = &self.config.default_file_filter {
-            for filter in &self.config.file_filters {
-                if filter.name == name.as_str() {
-                    self.selected_file_filter = Some(filter.id);
+        if !self.multi_selection_restored {
+            let selected: HashSet<&Path> = self
+                .multi_selection
+                .iter()
+                .map(DirectoryEntry::as_path)
+                .collect();
+
+            for item in self.directory_content.iter_mut() {
+                if selected.contains(item.as_path()) {
+                    item.selected = true;
                 }
             }
+
+            self.multi_selection_restored = true;
+            return;
         }
-    }
 
-    /// Selects the given file filter and applies the appropriate filters.
-    fn select_file_filter(&mut self, filter: Option<FileFilter>) {
-        self.selected_file_filter = filter.map(|f| f.id);
-        self.selected_item = None;
+        let content: HashMap<&Path, bool> = self
+            .directory_content
+            .iter()
+            .map(|p| (p.as_path(), p.selected))
+            .collect();
+
+        // Items that are not part of the current directory content are kept
+        self.multi_selection
+            .retain(|item| content.get(item.as_path()).copied().unwrap_or(true));
+
+        let mut selected: HashSet<PathBuf> = self
+            .multi_selection
+            .iter()
+            .map(DirectoryEntry::to_path_buf)
+            .collect();
+
+        for item in self.directory_content.iter().filter(|p| p.selected) {
+            if selected.insert(item.to_path_buf()) {
+                self.multi_selection.push(item.clone());
+            }
+        }
+    }
+
+    /// Returns the number of items in the multi selection.
+    /// For the items of the current directory, the selection state of the given
+    /// directory content is used, as it may have changed since the last synchronization.
+    fn count_multi_selection(&self, directory_content: &DirectoryContent) -> usize {
+        if self.mode != DialogMode::PickMultiple {
+            return 0;
+        }
+
+        if !self.multi_selection_restored {
+            return self.multi_selection.len();
+        }
+
+        let current_directory = self.current_directory();
+
+        let other_directories = self
+            .multi_selection
+            .iter()
+            .filter(|p| p.as_path().parent() != current_directory)
+            .count();
+
+        other_directories + directory_content.iter().filter(|p| p.selected).count()
+    }
+
+    /// Removes the given item from the multi selection.
+    fn deselect_multi_selection_item(&mut self, item: &DirectoryEntry) {
+        self.multi_selection.retain(|p| !p.path_eq(item));
+
+        if let Some(entry) = self.directory_content.iter_mut().find(|p| p.path_eq(item)) {
+            entry.selected = false;
+        }
+    }
+
+    /// Get the file filter the user currently selected.
+    fn get_selected_file_filter(&self) -> Option<&FileFilter> {
+        self.selected_file_filter
+            .and_then(|id| self.config.file_filters.iter().find(|p| p.id == id))
+    }
+
+    /// Sets the default file filter to use.
+    fn set_default_file_filter(&mut self) {
+        if let Some(name) = &self.config.default_file_filter {
+            for filter in &self.config.file_filters {
+                if filter.name == name.as_str() {
+                    self.selected_file_filter = Some(filter.id);
+                }
+            }
+        }
+    }
+
+    /// Selects the given file filter and applies the appropriate filters.
+    fn select_file_filter(&mut self, filter: Option<FileFilter>) {
+        if let Some(profile) = self.get_profile_mut() {
+            profile.file_filter = filter.as_ref().map(|f| f.name.clone());
+        }
+
+        self.selected_file_filter = filter.map(|f| f.id);
+        self.selected_item = None;
         self.refresh();
     }
 
+    /// Selects the file filter and save extension the user selected the last time
+    /// the dialog was opened with the current profile.
+    fn restore_profile_selection(&mut self) {
+        let Some(profile) = self.get_profile().cloned() else {
+            return;
+        };
+
+        if let Some(name) = &profile.file_filter {
+            if let Some(filter) = self.config.file_filters.iter().find(|f| &f.name == name) {
+                self.selected_file_filter = Some(filter.id);
+            }
+        }
+
+        if let Some(name) = &profile.save_extension {
+            if let Some(extension) = self
+                .config
+                .save_extensions
+                .iter()
+                .find(|e| &e.name == name)
+                .cloned()
+            {
+                self.selected_save_extension = Some(extension.id);
+                self.set_file_name_extension(&extension.file_extension);
+            }
+        }
+    }
+
     /// Get the save extension the user currently selected.
     fn get_selected_save_extension(&self) -> Option<&SaveExtension> {
         self.selected_save_extension
@@ -2973,6 +4928,10 @@ impl FileDialog {
 
     /// Selects the given save extension.
     fn select_save_extension(&mut self, extension: Option<SaveExtension>) {
+        if let Some(profile) = self.get_profile_mut() {
+            profile.save_extension = extension.as_ref().map(|e| e.name.clone());
+        }
+
         if let Some(ex) = extension {
             self.selected_save_extension = Some(ex.id);
             self.set_file_name_extension(&ex.file_extension);
@@ -3051,6 +5010,26 @@ impl FileDialog {
         self.storage.pinned_folders.push(pinned);
     }
 
+    /// Pins a path to the left sidebar at the given index and into the given group.
+    /// If the path is already pinned, the pinned folder is moved instead.
+    fn pin_path_at(&mut self, path: PathBuf, to: usize, group: Option<String>) {
+        if let Some(from) = self
+            .storage
+            .pinned_folders
+            .iter()
+            .position(|p| p.path == path)
+        {
+            self.move_pinned_folder(from, to, group);
+            return;
+        }
+
+        let mut pinned = PinnedFolder::from_path(path);
+        pinned.group = group;
+
+        let pinned_folders = &mut self.storage.pinned_folders;
+        pinned_folders.insert(to.min(pinned_folders.len()), pinned);
+    }
+
     /// Unpins a path from the left sidebar.
     fn unpin_path(&mut self, path: &Path) {
         self.storage
@@ -3058,6 +5037,114 @@ impl FileDialog {
             .retain(|p| p.path.as_path() != path);
     }
 
+    /// Moves the pinned folder at index `from` to index `to` and into the given group.
+    fn move_pinned_folder(&mut self, from: usize, to: usize, group: Option<String>) {
+        let pinned_folders = &mut self.storage.pinned_folders;
+
+        if from >= pinned_folders.len() {
+            return;
+        }
+
+        let mut pinned = pinned_folders.remove(from);
+        pinned.group = group;
+
+        let to = if from < to { to - 1 } else { to };
+        pinned_folders.insert(to.min(pinned_folders.len()), pinned);
+    }
+
+    /// Saves the storage to the storage file, if set, when the settings of the user
+    /// changed. Other changes, like the directories the user visited, are only saved
+    /// when the dialog is closed, so that navigating does not write the file every time.
+    #[cfg(feature = "persistence")]
+    fn autosave_storage(&mut self) {
+        let Some(saved) = &self.saved_storage else {
+            return;
+        };
+
+        let changed = if self.state == DialogState::Open {
+            !saved.settings_eq(&self.storage)
+        } else {
+            *saved != self.storage
+        };
+
+        // Errors are ignored, as failing to save the storage should not affect the dialog.
+        // The storage is not saved again until it changes.
+        if changed && self.save_storage().is_err() {
+            self.saved_storage = Some(self.storage.clone());
+        }
+    }
+
+    /// Synchronizes the settings of the storage with the storage shared with other dialogs.
+    /// If the settings changed since the last synchronization, they are written to the
+    /// shared storage. Otherwise, changes made by other dialogs are applied.
+    /// Changed directories and profiles are written to the shared storage as well.
+    fn sync_shared_storage(&mut self) {
+        let Some(shared) = &self.shared_storage else {
+            return;
+        };
+
+        let synced = self.synced_storage.as_ref();
+        let changed = synced.is_none_or(|synced| !synced.settings_eq(&self.storage));
+        let directories_changed = synced.is_none_or(|synced| !synced.directories_eq(&self.storage));
+
+        if directories_changed {
+            shared.write_directories(&self.storage);
+        }
+
+        if changed {
+            self.synced_generation = shared.write_settings(&self.storage);
+        } else if shared.generation() != self.synced_generation {
+            self.synced_generation = shared.read_settings(&mut self.storage);
+        } else if !directories_changed {
+            return;
+        }
+
+        self.synced_storage = Some(self.storage.clone());
+    }
+
+    /// Sets the quick filter applied to the directory content.
+    /// Items hidden by the filter are removed from the selection.
+    fn set_quick_filter(&mut self, quick_filter: QuickFilter) {
+        let matcher = quick_filter.matcher();
+
+        if self
+            .selected_item
+            .as_ref()
+            .is_some_and(|item| !matcher.matches(item))
+        {
+            self.selected_item = None;
+        }
+
+        for item in self.directory_content.iter_mut() {
+            if item.selected && !matcher.matches(item) {
+                item.selected = false;
+            }
+        }
+
+        self.sync_multi_selection();
+        self.directory_content.set_quick_filter(quick_filter);
+    }
+
+    /// Checks which pinned folders no longer exist.
+    fn update_missing_pinned_folders(&mut self) {
+        self.missing_pinned_folders = self
+            .storage
+            .pinned_folders
+            .iter()
+            .filter(|p| !self.config.file_system.is_dir(&p.path))
+            .map(|p| p.path.clone())
+            .collect();
+    }
+
+    /// Unpins all folders that no longer exist.
+    fn remove_missing_pinned_folders(&mut self) {
+        let missing = std::mem::take(&mut self.missing_pinned_folders);
+
+        self.storage
+            .pinned_folders
+            .retain(|p| !missing.contains(&p.path));
+    }
+
     /// Checks if the path is pinned to the left sidebar.
     fn is_pinned(&self, path: &Path) -> bool {
         self.storage
@@ -3105,8 +5192,26 @@ impl FileDialog {
     fn reset(&mut self) {
         let storage = self.storage.clone();
         let config = self.config.clone();
+        // Keep the cached directory sizes
+        let directory_sizes = std::mem::take(&mut self.directory_sizes);
+        let shared_storage = std::mem::take(&mut self.shared_storage);
+        let synced_storage = std::mem::take(&mut self.synced_storage);
+        let synced_generation = self.synced_generation;
+        #[cfg(feature = "persistence")]
+        let storage_file = std::mem::take(&mut self.storage_file);
+        #[cfg(feature = "persistence")]
+        let saved_storage = std::mem::take(&mut self.saved_storage);
         *self = Self::with_config(config);
         self.storage = storage;
+        self.directory_sizes = directory_sizes;
+        self.shared_storage = shared_storage;
+        self.synced_storage = synced_storage;
+        self.synced_generation = synced_generation;
+        #[cfg(feature = "persistence")]
+        {
+            self.storage_file = storage_file;
+            self.saved_storage = saved_storage;
+        }
     }
 
     /// Refreshes the dialog.
@@ -3120,21 +5225,60 @@ impl FileDialog {
             .config
             .file_system
             .get_disks(self.config.canonicalize_paths);
+        self.last_disk_scan = None;
+
+        self.update_missing_pinned_folders();
+
+        self.system_bookmarks = None;
+        if self.config.show_system_bookmarks {
+            if let Some(home_dir) = self.user_directories.as_ref().and_then(|d| d.home_dir()) {
+                self.system_bookmarks = load_system_bookmarks(
+                    &self.config.labels.heading_bookmarks,
+                    &*self.config.file_system,
+                    home_dir,
+                    self.config.canonicalize_paths,
+                );
+            }
+        }
+
+        #[cfg(feature = "recently_used")]
+        {
+            self.recent_files = None;
+            self.filtered_recent_files = None;
+            if self.config.show_recent_files {
+                if let Some(home_dir) = self.user_directories.as_ref().and_then(|d| d.home_dir()) {
+                    self.recent_files = load_recent_files(
+                        &self.config.labels.heading_recent,
+                        &*self.config.file_system,
+                        home_dir,
+                        self.config.canonicalize_paths,
+                    );
+                }
+            }
+        }
 
         self.reload_directory();
     }
 
     /// Submits the current selection and tries to finish the dialog, if the selection is valid.
     fn submit(&mut self) {
+        // Include the items selected in the current directory since the last update
+        // before validating the multi selection.
+        self.sync_multi_selection();
+
         // Make sure the selected item or entered file name is valid.
         if !self.is_selection_valid() {
             return;
         }
 
-        self.storage.last_picked_dir = self.current_directory().map(PathBuf::from);
+        let last_picked_dir = self.current_directory().map(PathBuf::from);
+        match self.get_profile_mut() {
+            Some(profile) => profile.last_picked_dir = last_picked_dir,
+            None => self.storage.last_picked_dir = last_picked_dir,
+        }
 
         match &self.mode {
-            DialogMode::PickDirectory | DialogMode::PickFile => {
+            DialogMode::PickDirectory | DialogMode::PickFile | DialogMode::PickFileOrDirectory => {
                 // Should always contain a value since `is_selection_valid` is used to
                 // validate the selection.
                 if let Some(item) = self.selected_item.clone() {
@@ -3183,16 +5327,21 @@ impl FileDialog {
     ///   - Canonicalize the path if enabled
     ///   - Attempts to use the parent directory if the path is a file
     fn get_initial_directory(&self) -> PathBuf {
+        let (last_visited_dir, last_picked_dir) = match self.get_profile() {
+            Some(profile) => (&profile.last_visited_dir, &profile.last_picked_dir),
+            None if self.profile.is_some() => (&None, &None),
+            None => (
+                &self.storage.last_visited_dir,
+                &self.storage.last_picked_dir,
+            ),
+        };
+
         let path = match self.config.opening_mode {
             OpeningMode::AlwaysInitialDir => &self.config.initial_directory,
-            OpeningMode::LastVisitedDir => self
-                .storage
-                .last_visited_dir
+            OpeningMode::LastVisitedDir => last_visited_dir
                 .as_deref()
                 .unwrap_or(&self.config.initial_directory),
-            OpeningMode::LastPickedDir => self
-                .storage
-                .last_picked_dir
+            OpeningMode::LastPickedDir => last_picked_dir
                 .as_deref()
                 .unwrap_or(&self.config.initial_directory),
         };
@@ -3208,6 +5357,20 @@ impl FileDialog {
         path
     }
 
+    /// Gets the data of the profile the dialog was opened with.
+    /// None if no profile is used or nothing was stored for the profile yet.
+    fn get_profile(&self) -> Option<&DialogProfile> {
+        self.storage.profiles.get(self.profile.as_ref()?)
+    }
+
+    /// Gets the data of the profile the dialog was opened with, creating it if necessary.
+    /// None if no profile is used.
+    fn get_profile_mut(&mut self) -> Option<&mut DialogProfile> {
+        let profile = self.profile.as_ref()?;
+
+        Some(self.storage.profiles.entry(profile.clone()).or_default())
+    }
+
     /// Gets the currently open directory.
     fn current_directory(&self) -> Option<&Path> {
         if let Some(x) = self.directory_stack.iter().nth_back(self.directory_offset) {
@@ -3229,11 +5392,22 @@ impl FileDialog {
                 .selected_item
                 .as_ref()
                 .is_some_and(DirectoryEntry::is_file),
-            DialogMode::PickMultiple => self.get_dir_content_filtered_iter().any(|p| p.selected),
+            DialogMode::PickFileOrDirectory => self.selected_item.is_some(),
+            DialogMode::PickMultiple => self.validate_multi_selection().is_none(),
             DialogMode::SaveFile => self.file_name_input_error.is_none(),
         }
     }
 
+    /// Validates the items the user selected in `DialogMode::PickMultiple` mode using
+    /// the configured selection constraints.
+    ///
+    /// Returns None if the selection is valid. Otherwise returns an error message.
+    fn validate_multi_selection(&self) -> Option<String> {
+        self.config
+            .selection_constraints
+            .validate(self.selected_entries(), &self.config.labels)
+    }
+
     /// Validates the file name entered by the user.
     ///
     /// Returns None if the file name is valid. Otherwise returns an error message.
@@ -3246,12 +5420,22 @@ impl FileDialog {
             let mut full_path = x.to_path_buf();
             full_path.push(self.file_name_input.as_str());
 
+            let name = [("name", self.file_name_input.as_str())];
+
             if self.config.file_system.is_dir(&full_path) {
-                return Some(self.config.labels.err_directory_exists.clone());
+                return Some(
+                    self.config
+                        .labels
+                        .format(&self.config.labels.err_directory_exists, &name),
+                );
             }
 
             if !self.config.allow_file_overwrite && self.config.file_system.is_file(&full_path) {
-                return Some(self.config.labels.err_file_exists.clone());
+                return Some(
+                    self.config
+                        .labels
+                        .format(&self.config.labels.err_file_exists, &name),
+                );
             }
         } else {
             // There is most likely a bug in the code if we get this error message!
@@ -3265,7 +5449,7 @@ impl FileDialog {
     /// Also updates the `file_name_input` to the name of the selected item.
     fn select_item(&mut self, item: &mut DirectoryEntry) {
         if self.mode == DialogMode::PickMultiple {
-            item.selected = true;
+            item.selected = self.config.selection_constraints.allows(item);
         }
         self.selected_item = Some(item.clone());
 
@@ -3377,6 +5561,59 @@ impl FileDialog {
         self.directory_content = directory_content;
     }
 
+    /// Selects the next visible item whose file name starts with the text in
+    /// `type_ahead_buffer`.
+    ///
+    /// If only a single character was typed, the search starts after the currently selected
+    /// item, so that pressing the same key repeatedly cycles through the matching items.
+    /// Otherwise the currently selected item is kept as long as it still matches.
+    fn select_type_ahead_item(&mut self) {
+        let prefix = self.type_ahead_buffer.to_lowercase();
+
+        let mut directory_content = std::mem::take(&mut self.directory_content);
+        let search_value = std::mem::take(&mut self.search_value);
+
+        let selected_index = self.selected_item.as_ref().and_then(|selected| {
+            directory_content
+                .filtered_iter(&search_value)
+                .position(|p| p.path_eq(selected))
+        });
+
+        let start = match selected_index {
+            Some(i) if prefix.chars().count() == 1 => i + 1,
+            Some(i) => i,
+            None => 0,
+        };
+
+        let matches: Vec<usize> = directory_content
+            .filtered_iter(&search_value)
+            .enumerate()
+            .filter(|(_, p)| p.file_name().to_lowercase().starts_with(&prefix))
+            .map(|(i, _)| i)
+            .collect();
+
+        let index = matches
+            .iter()
+            .find(|i| **i >= start)
+            .or_else(|| matches.first())
+            .copied();
+
+        if let Some(index) = index {
+            directory_content.reset_multi_selection();
+
+            if let Some(item) = directory_content
+                .filtered_iter_mut(&search_value)
+                .nth(index)
+            {
+                self.select_item(item);
+                self.scroll_to_selection = true;
+            }
+        }
+
+        self.directory_content = directory_content;
+        self.search_value = search_value;
+    }
+
     /// Opens the text field in the top panel to text edit the current path.
     fn open_path_edit(&mut self) {
         let path = self.current_directory().map_or_else(String::new, |path| {
@@ -3394,7 +5631,9 @@ impl FileDialog {
 
         let path = self.canonicalize_path(&PathBuf::from(&self.path_edit_value));
 
-        if self.mode == DialogMode::PickFile && self.config.file_system.is_file(&path) {
+        if (self.mode == DialogMode::PickFile || self.mode == DialogMode::PickFileOrDirectory)
+            && self.config.file_system.is_file(&path)
+        {
             self.state = DialogState::Picked(path);
             return;
         }
@@ -3509,14 +5748,34 @@ impl FileDialog {
         // Clear the entry filter buffer.
         // It's unlikely the user wants to keep the current filter when entering a new directory.
         self.search_value.clear();
+        self.type_ahead_buffer.clear();
     }
 
     /// Loads the directory content of the given path.
     fn load_directory_content(&mut self, path: &Path) {
-        self.storage.last_visited_dir = Some(path.to_path_buf());
+        match self.get_profile_mut() {
+            Some(profile) => profile.last_visited_dir = Some(path.to_path_buf()),
+            None => self.storage.last_visited_dir = Some(path.to_path_buf()),
+        }
+
+        // Keep the items the user selected in the previous directory content
+        self.sync_multi_selection();
+        self.multi_selection_restored = false;
+
+        // Stop calculating the size of directories that are no longer visible,
+        // except for the directories in the multi selection.
+        let selected: HashSet<&Path> = self
+            .multi_selection
+            .iter()
+            .map(DirectoryEntry::as_path)
+            .collect();
+        self.directory_sizes
+            .cancel_except(|path| selected.contains(path));
 
         let selected_file_filter = match self.mode {
-            DialogMode::PickFile | DialogMode::PickMultiple => self.get_selected_file_filter(),
+            DialogMode::PickFile | DialogMode::PickFileOrDirectory | DialogMode::PickMultiple => {
+                self.get_selected_file_filter()
+            }
             _ => None,
         };
 
@@ -3533,6 +5792,7 @@ impl FileDialog {
             show_system_files: self.storage.show_system_files,
             file_filter: selected_file_filter.cloned(),
             filter_extension: selected_save_extension.map(str::to_string),
+            quick_filter: self.quick_filter_bar.filter().clone(),
         };
 
         self.directory_content = DirectoryContent::from_path(
@@ -3550,3 +5810,699 @@ impl FileDialog {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[cfg(feature = "serde")]
+    fn fixture(name: &str) -> Result<FileDialogStorage, Box<dyn std::error::Error>> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/storage")
+            .join(name);
+
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loads_unversioned_storage() -> Result<(), Box<dyn std::error::Error>> {
+        let mut storage = fixture("v0.11.0.json")?;
+
+        assert_eq!(storage.version, 0);
+        assert_eq!(storage.pinned_folders.len(), 2);
+        assert_eq!(storage.pinned_folders[1].label, "Renders");
+        assert_eq!(storage.pinned_folders[1].group, None);
+        assert!(storage.show_hidden);
+        assert_eq!(
+            storage.last_picked_dir.as_deref(),
+            Some(Path::new("/home/user/Projects"))
+        );
+        assert!(storage.profiles.is_empty());
+
+        storage.migrate();
+        assert_eq!(storage.version, FileDialogStorage::VERSION);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn ignores_unknown_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let mut storage = fixture("newer.json")?;
+
+        assert_eq!(storage.version, FileDialogStorage::VERSION + 1);
+        assert_eq!(storage.pinned_folders.len(), 1);
+        assert!(storage.show_system_files);
+
+        // Data of a newer version is not changed
+        storage.migrate();
+        assert_eq!(storage.version, FileDialogStorage::VERSION + 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip() -> serde_json::Result<()> {
+        let mut storage = FileDialogStorage {
+            pinned_folders: vec![PinnedFolder::from_path(PathBuf::from("/home/user/Music"))
+                .with_group("Media")
+                .with_icon("🎵")
+                .with_color(egui::Color32::RED)],
+            show_hidden: true,
+            last_visited_dir: Some(PathBuf::from("/home/user")),
+            place_order: vec![UserDirectory::Downloads, UserDirectory::Home],
+            ..Default::default()
+        };
+        storage.profiles.insert(
+            "import_mesh".to_string(),
+            DialogProfile {
+                last_picked_dir: Some(PathBuf::from("/home/user/Meshes")),
+                file_filter: Some("OBJ files".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let json = serde_json::to_string(&storage)?;
+        let loaded: FileDialogStorage = serde_json::from_str(&json)?;
+
+        assert_eq!(loaded, storage);
+
+        Ok(())
+    }
+
+    /// Updates the dialog for a single frame with the given input events.
+    #[cfg(feature = "persistence")]
+    fn run_frame(ctx: &egui::Context, dialog: &mut FileDialog, events: Vec<egui::Event>) {
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+
+        let _ = ctx.run(input, |ctx| {
+            dialog.update(ctx);
+        });
+    }
+
+    #[cfg(feature = "persistence")]
+    #[test]
+    fn storage_file_is_not_overwritten_if_invalid() -> std::io::Result<()> {
+        let dir = TempDir::new("storage-file-invalid")?;
+        let file = crate::StorageFile::new(dir.join("storage.json"), crate::StorageFormat::Json);
+
+        // A missing file is created when the storage changes
+        assert!(FileDialog::new().storage_file(file.clone()).is_ok());
+
+        std::fs::write(file.path(), "{ invalid")?;
+        assert!(FileDialog::new().storage_file(file.clone()).is_err());
+        assert_eq!(std::fs::read_to_string(file.path())?, "{ invalid");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "persistence")]
+    #[test]
+    fn storage_file_is_saved_when_settings_change() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("storage-file-autosave", &["folder/"])?;
+        let file = crate::StorageFile::new(dir.join("storage.json"), crate::StorageFormat::Json);
+        let ctx = egui::Context::default();
+
+        let mut dialog = FileDialog::new()
+            .load_via_thread(false)
+            .initial_directory(dir.to_path_buf())
+            .storage_file(file.clone())?;
+        dialog.pick_file();
+
+        // Navigating does not write the file
+        dialog.load_directory(&dir.join("folder"));
+        run_frame(&ctx, &mut dialog, Vec::new());
+        assert!(!file.path().exists());
+
+        dialog.pin_path(dir.to_path_buf());
+        run_frame(&ctx, &mut dialog, Vec::new());
+        assert_eq!(file.load()?.pinned_folders, dialog.storage.pinned_folders);
+
+        dialog.load_directory(&dir);
+        run_frame(&ctx, &mut dialog, Vec::new());
+        assert_eq!(file.load()?.last_visited_dir, Some(dir.join("folder")));
+
+        // The visited directories are saved when the user closes the dialog
+        let escape = egui::Event::Key {
+            key: egui::Key::Escape,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run_frame(&ctx, &mut dialog, vec![escape]);
+        assert_eq!(dialog.state(), DialogState::Cancelled);
+        assert_eq!(file.load()?.last_visited_dir, Some(dir.to_path_buf()));
+
+        Ok(())
+    }
+
+    /// Returns the texts the dialog displays in the next frame.
+    fn displayed_texts(dialog: &mut FileDialog) -> Vec<String> {
+        fn collect(shape: &egui::Shape, texts: &mut Vec<String>) {
+            match shape {
+                egui::Shape::Text(text) => texts.push(text.galley.text().to_string()),
+                egui::Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        collect(shape, texts);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let ctx = egui::Context::default();
+        let mut texts = Vec::new();
+
+        // The first frame is used by egui to determine the size of the window
+        for _ in 0..2 {
+            texts.clear();
+            let output = ctx.run(egui::RawInput::default(), |ctx| {
+                dialog.update(ctx);
+            });
+
+            for shape in &output.shapes {
+                collect(&shape.shape, &mut texts);
+            }
+        }
+
+        texts
+    }
+
+    #[test]
+    fn quick_filter_hides_entries() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("quick-filter-hides", &["folder/"])?;
+        std::fs::write(dir.join("small.txt"), [0; 10])?;
+        std::fs::write(dir.join("large.txt"), [0; 1000])?;
+
+        let mut dialog = open_dialog(&dir, FileDialog::pick_multiple);
+        assert!(displayed_texts(&mut dialog)
+            .iter()
+            .any(|t| t.contains("small.txt")));
+
+        select_in_directory(&mut dialog, "small.txt");
+        dialog.selected_item = dialog
+            .directory_content
+            .iter()
+            .find(|p| p.file_name() == "small.txt")
+            .cloned();
+        dialog.sync_multi_selection();
+        assert_eq!(dialog.multi_selection.len(), 1);
+
+        dialog.set_quick_filter(QuickFilter {
+            min_size: Some(100),
+            ..Default::default()
+        });
+
+        let texts = displayed_texts(&mut dialog);
+        assert!(!texts.iter().any(|t| t.contains("small.txt")));
+        assert!(texts.iter().any(|t| t.contains("large.txt")));
+        assert!(texts.iter().any(|t| t.contains("folder")));
+
+        // Hidden items are no longer selected
+        assert_eq!(selected_name(&dialog), None);
+        assert!(dialog.multi_selection.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn profiles_remember_directories_filters_and_extensions() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("profiles", &["meshes/", "renders/"])?;
+        let (meshes, renders) = (dir.join("meshes"), dir.join("renders"));
+
+        let mut dialog = FileDialog::new()
+            .load_via_thread(false)
+            .initial_directory(dir.to_path_buf())
+            .opening_mode(OpeningMode::LastVisitedDir)
+            .add_file_filter_extensions("OBJ", vec!["obj"])
+            .add_file_filter_extensions("PNG", vec!["png"])
+            .add_save_extension("PNG image", "png")
+            .add_save_extension("JPEG image", "jpg");
+
+        let file_filter =
+            |dialog: &FileDialog| dialog.get_selected_file_filter().map(|f| f.name.clone());
+        let save_extension =
+            |dialog: &FileDialog| dialog.get_selected_save_extension().map(|e| e.name.clone());
+
+        dialog.pick_file_with_profile("import_mesh");
+        dialog.load_directory(&meshes);
+        let filter = dialog
+            .config
+            .file_filters
+            .iter()
+            .find(|f| f.name == "OBJ")
+            .cloned();
+        dialog.select_file_filter(filter);
+
+        dialog.save_file_with_profile("export_render");
+        assert_eq!(dialog.profile(), Some("export_render"));
+        assert_eq!(dialog.current_directory(), Some(&*dir));
+        assert_eq!(file_filter(&dialog), None);
+        dialog.load_directory(&renders);
+        let extension = dialog.config.save_extensions.get(1).cloned();
+        dialog.select_save_extension(extension);
+
+        dialog.pick_file_with_profile("import_mesh");
+        assert_eq!(dialog.current_directory(), Some(meshes.as_path()));
+        assert_eq!(file_filter(&dialog).as_deref(), Some("OBJ"));
+
+        dialog.save_file_with_profile("export_render");
+        assert_eq!(dialog.current_directory(), Some(renders.as_path()));
+        assert_eq!(save_extension(&dialog).as_deref(), Some("JPEG image"));
+        assert_eq!(dialog.file_name_input, "Untitled.jpg");
+
+        // Navigating with a profile does not change the directories remembered
+        // without a profile
+        dialog.pick_file();
+        assert_eq!(dialog.profile(), None);
+        assert_eq!(dialog.current_directory(), Some(&*dir));
+        assert_eq!(file_filter(&dialog), None);
+        assert_eq!(dialog.storage.profiles.len(), 2);
+
+        Ok(())
+    }
+
+    /// Creates a dialog that loads directories on the current thread and
+    /// opens it in the given directory.
+    fn open_dialog(dir: &Path, open: impl FnOnce(&mut FileDialog)) -> FileDialog {
+        let mut dialog = FileDialog::new()
+            .load_via_thread(false)
+            .initial_directory(dir.to_path_buf());
+        open(&mut dialog);
+        dialog
+    }
+
+    fn selected_name(dialog: &FileDialog) -> Option<&str> {
+        dialog.selected_item.as_ref().map(DirectoryEntry::file_name)
+    }
+
+    /// Marks the item with the given name in the current directory as selected.
+    fn select_in_directory(dialog: &mut FileDialog, name: &str) {
+        if let Some(item) = dialog
+            .directory_content
+            .iter_mut()
+            .find(|p| p.file_name() == name)
+        {
+            item.selected = true;
+        }
+    }
+
+    fn picked_names(dialog: &FileDialog) -> Vec<String> {
+        match dialog.state() {
+            DialogState::PickedMultiple(paths) => paths
+                .iter()
+                .filter_map(|p| p.file_name())
+                .map(|n| n.to_string_lossy().into_owned())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn shift_click_ignores_disallowed_kinds() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("shift-click", &["a.txt", "b.txt", "folder/"])?;
+
+        let mut dialog = open_dialog(&dir, |dialog| {
+            dialog.config.selection_constraints.selectable_entries =
+                crate::SelectableEntries::Files;
+            dialog.pick_multiple();
+        });
+
+        let entry = |dialog: &FileDialog, name: &str| {
+            dialog
+                .directory_content
+                .iter()
+                .find(|p| p.file_name() == name)
+                .cloned()
+        };
+
+        let (Some(mut a), Some(mut b), Some(mut folder)) = (
+            entry(&dialog, "a.txt"),
+            entry(&dialog, "b.txt"),
+            entry(&dialog, "folder"),
+        ) else {
+            return Err(std::io::ErrorKind::NotFound.into());
+        };
+
+        dialog.select_item(&mut a);
+
+        let from = dialog.shift_select_item(&mut folder, 1);
+        assert_eq!(from.as_ref().map(DirectoryEntry::file_name), Some("a.txt"));
+        assert_eq!(selected_name(&dialog), Some("a.txt"));
+        assert!(!folder.selected);
+
+        let from = dialog.shift_select_item(&mut b, 1);
+        assert_eq!(from.as_ref().map(DirectoryEntry::file_name), Some("a.txt"));
+        assert_eq!(selected_name(&dialog), Some("b.txt"));
+        assert!(b.selected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pick_file_or_directory_accepts_both_kinds() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("pick-file-or-directory", &["file.txt", "folder/"])?;
+        let mut dialog = open_dialog(&dir, FileDialog::pick_file_or_directory);
+
+        assert!(!dialog.is_selection_valid());
+
+        for name in ["file.txt", "folder"] {
+            let Some(mut item) = dialog
+                .directory_content
+                .iter()
+                .find(|p| p.file_name() == name)
+                .cloned()
+            else {
+                return Err(std::io::ErrorKind::NotFound.into());
+            };
+
+            dialog.select_item(&mut item);
+            assert_eq!(selected_name(&dialog), Some(name));
+            assert!(dialog.is_selection_valid());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn pick_file_or_directory_path_edit() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("pick-file-or-directory-path", &["file.txt", "folder/"])?;
+
+        // An existing file is picked
+        let mut dialog = open_dialog(&dir, FileDialog::pick_file_or_directory);
+        dialog.path_edit_value = dir.join("file.txt").to_string_lossy().into_owned();
+        dialog.submit_path_edit();
+
+        assert_eq!(dialog.state(), DialogState::Picked(dir.join("file.txt")));
+
+        // A directory is opened
+        let mut dialog = open_dialog(&dir, FileDialog::pick_file_or_directory);
+        dialog.path_edit_value = dir.join("folder").to_string_lossy().into_owned();
+        dialog.submit_path_edit();
+
+        assert_eq!(dialog.state(), DialogState::Open);
+        assert_eq!(
+            dialog.current_directory(),
+            Some(dir.join("folder").as_path())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn multi_selection_across_directories() -> std::io::Result<()> {
+        let dir = TempDir::with_entries(
+            "multi-selection",
+            &["a/", "a/one.txt", "a/two.txt", "b/", "b/three.txt"],
+        )?;
+        let mut dialog = open_dialog(&dir.join("a"), FileDialog::pick_multiple);
+        dialog.config.selection_constraints.min_items = 2;
+
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "one.txt");
+
+        dialog.load_directory(&dir.join("b"));
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "three.txt");
+
+        // The selection of the current directory is not yet synchronized
+        assert_eq!(dialog.multi_selection.len(), 1);
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 2);
+
+        dialog.load_directory(&dir.join("a"));
+        assert_eq!(dialog.multi_selection.len(), 2);
+        dialog.sync_multi_selection();
+        assert!(dialog
+            .directory_content
+            .iter()
+            .any(|p| p.file_name() == "one.txt" && p.selected));
+
+        dialog.submit();
+        assert_eq!(picked_names(&dialog), ["one.txt", "three.txt"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn submit_includes_unsynchronized_selection() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("multi-selection-submit", &["one.txt", "two.txt"])?;
+        let mut dialog = open_dialog(&dir, FileDialog::pick_multiple);
+        dialog.config.selection_constraints.min_items = 2;
+
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "one.txt");
+        select_in_directory(&mut dialog, "two.txt");
+
+        dialog.submit();
+        assert_eq!(picked_names(&dialog), ["one.txt", "two.txt"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn multi_selection_summary_without_directory_sizes() -> std::io::Result<()> {
+        let dir = TempDir::with_entries(
+            "multi-selection-summary",
+            &["one.txt", "folder/", "folder/two.txt"],
+        )?;
+        let mut dialog = open_dialog(&dir, |dialog| {
+            dialog.config.calculate_directory_sizes = false;
+            dialog.pick_multiple();
+        });
+
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "one.txt");
+        select_in_directory(&mut dialog, "folder");
+        dialog.sync_multi_selection();
+
+        let ctx = egui::Context::default();
+        assert_eq!(
+            dialog.get_multi_selection_summary(&ctx),
+            "1 file (7 B), 1 folder"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn counts_multi_selection() -> std::io::Result<()> {
+        let dir = TempDir::with_entries(
+            "multi-selection-count",
+            &["a/", "a/one.txt", "b/", "b/two.txt"],
+        )?;
+        let mut dialog = open_dialog(&dir.join("a"), FileDialog::pick_multiple);
+
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 0);
+
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "one.txt");
+        dialog.load_directory(&dir.join("b"));
+
+        // Before the selection is restored, only the stored selection is counted
+        select_in_directory(&mut dialog, "two.txt");
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 1);
+
+        dialog.sync_multi_selection();
+        select_in_directory(&mut dialog, "two.txt");
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 2);
+
+        // Deselected items of the current directory are no longer counted
+        dialog.sync_multi_selection();
+        for item in dialog.directory_content.iter_mut() {
+            item.selected = false;
+        }
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 1);
+
+        // Other modes do not have a multi selection
+        let dialog = open_dialog(&dir.join("a"), FileDialog::pick_file);
+        assert_eq!(dialog.count_multi_selection(&dialog.directory_content), 0);
+
+        Ok(())
+    }
+
+    fn pinned_groups(dialog: &FileDialog) -> Vec<(&str, Option<&str>)> {
+        dialog
+            .storage
+            .pinned_folders
+            .iter()
+            .map(|p| (p.label.as_str(), p.group.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn moves_pinned_folders_between_groups() {
+        let mut dialog = FileDialog::new();
+        dialog.storage.pinned_folders = ["a", "b", "c"]
+            .into_iter()
+            .map(|name| PinnedFolder::from_path(PathBuf::from(format!("/{name}"))))
+            .collect();
+        dialog.storage.pinned_folders[1].group = Some("Media".to_string());
+        dialog.storage.pinned_folders[2].group = Some("Media".to_string());
+
+        // Dropped after the last folder of the group
+        dialog.move_pinned_folder(0, 3, Some("Media".to_string()));
+        assert_eq!(
+            pinned_groups(&dialog),
+            [
+                ("b", Some("Media")),
+                ("c", Some("Media")),
+                ("a", Some("Media"))
+            ]
+        );
+
+        // Dropped before the first folder, out of the group
+        dialog.move_pinned_folder(1, 0, None);
+        assert_eq!(
+            pinned_groups(&dialog),
+            [("c", None), ("b", Some("Media")), ("a", Some("Media"))]
+        );
+
+        // Moved into a new group using the context menu, keeping the position
+        dialog.move_pinned_folder(1, 1, Some("Work".to_string()));
+        assert_eq!(
+            pinned_groups(&dialog),
+            [("c", None), ("b", Some("Work")), ("a", Some("Media"))]
+        );
+
+        // Invalid indices are ignored
+        dialog.move_pinned_folder(3, 0, None);
+        assert_eq!(dialog.storage.pinned_folders.len(), 3);
+    }
+
+    #[test]
+    fn type_ahead_selects_by_prefix() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("type-ahead", &["apple.txt", "avocado.txt", "banana.txt"])?;
+        let mut dialog = open_dialog(&dir, FileDialog::pick_file);
+
+        dialog.type_ahead_input("b", 1.0);
+        assert_eq!(selected_name(&dialog), Some("banana.txt"));
+
+        // Repeating a single letter cycles through the matching items
+        dialog.type_ahead_input("a", 5.0);
+        assert_eq!(selected_name(&dialog), Some("apple.txt"));
+        dialog.type_ahead_input("a", 10.0);
+        assert_eq!(selected_name(&dialog), Some("avocado.txt"));
+        dialog.type_ahead_input("a", 15.0);
+        assert_eq!(selected_name(&dialog), Some("apple.txt"));
+
+        // Letters typed within the timeout are combined and matched case-insensitively
+        dialog.type_ahead_input("A", 20.0);
+        dialog.type_ahead_input("V", 20.1);
+        assert_eq!(selected_name(&dialog), Some("avocado.txt"));
+        assert_eq!(dialog.type_ahead_buffer, "AV");
+
+        // Input without a match keeps the selection
+        dialog.type_ahead_input("x", 20.2);
+        assert_eq!(selected_name(&dialog), Some("avocado.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_ahead_buffer_times_out() -> std::io::Result<()> {
+        let dir = TempDir::with_entries("type-ahead-timeout", &["apple.txt", "banana.txt"])?;
+        let mut dialog = open_dialog(&dir, FileDialog::pick_file);
+        let timeout = dialog.config.type_ahead_timeout.as_secs_f64();
+
+        dialog.type_ahead_input("a", 1.0);
+        dialog.type_ahead_input("p", 1.0 + timeout / 2.0);
+        assert_eq!(dialog.type_ahead_buffer, "ap");
+
+        dialog.type_ahead_input("b", 2.0 + timeout);
+        assert_eq!(dialog.type_ahead_buffer, "b");
+        assert_eq!(selected_name(&dialog), Some("banana.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mime_filter_uses_current_database() {
+        let mut database = MimeDatabase::new();
+        database.add_extension("cus", "image/x-custom");
+
+        // The database is set after the filter was added
+        let dialog = FileDialog::new()
+            .add_file_filter_mime("Custom", &["image/x-custom"])
+            .mime_database(database);
+
+        let filter = dialog.config.file_filters.first();
+
+        assert!(filter.is_some_and(|f| f.matches(Path::new("/tmp/a.cus"), &dialog.config)));
+        assert!(!filter.is_some_and(|f| f.matches(Path::new("/tmp/a.png"), &dialog.config)));
+    }
+
+    #[test]
+    fn syncs_shared_storage() {
+        let shared = SharedStorage::default();
+        let mut first = FileDialog::new().shared_storage(shared.clone());
+        let mut second = FileDialog::new().shared_storage(shared.clone());
+
+        first.pin_path(PathBuf::from("/home/user/Projects"));
+        first.sync_shared_storage();
+        second.sync_shared_storage();
+
+        assert!(second.is_pinned(Path::new("/home/user/Projects")));
+
+        second.storage_mut().show_hidden = true;
+        second.sync_shared_storage();
+        first.sync_shared_storage();
+
+        assert!(first.storage_mut().show_hidden);
+        assert!(shared.get().settings_eq(&first.storage));
+
+        // Unchanged settings do not change the generation
+        let generation = shared.generation();
+        first.sync_shared_storage();
+        second.sync_shared_storage();
+        assert_eq!(shared.generation(), generation);
+
+        // The directories are kept per dialog, but written to the shared storage
+        first.storage_mut().last_visited_dir = Some(PathBuf::from("/home/user/Projects"));
+        first.storage_mut().show_system_files = true;
+        first.sync_shared_storage();
+        second.sync_shared_storage();
+
+        assert!(second.storage.show_system_files);
+        assert_eq!(second.storage.last_visited_dir, None);
+        assert_eq!(
+            shared.get().last_visited_dir,
+            Some(PathBuf::from("/home/user/Projects"))
+        );
+
+        // The profiles of all dialogs are merged
+        first
+            .storage_mut()
+            .profiles
+            .insert("images".to_string(), DialogProfile::default());
+        second.storage_mut().profiles.insert(
+            "documents".to_string(),
+            DialogProfile {
+                last_visited_dir: Some(PathBuf::from("/home/user/Documents")),
+                ..Default::default()
+            },
+        );
+        let generation = shared.generation();
+        first.sync_shared_storage();
+        second.sync_shared_storage();
+
+        assert_eq!(shared.generation(), generation);
+        let mut profiles: Vec<String> = shared.get().profiles.into_keys().collect();
+        profiles.sort();
+        assert_eq!(profiles, ["documents", "images"]);
+        assert!(!first.storage.profiles.contains_key("documents"));
+
+        // Replacing the shared storage is applied by all dialogs
+        shared.set(FileDialogStorage::default());
+        first.sync_shared_storage();
+        assert!(!first.storage.show_hidden);
+        assert!(first.storage.pinned_folders.is_empty());
+        assert!(first.storage.last_visited_dir.is_some());
+    }
+}
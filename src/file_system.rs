@@ -1,5 +1,6 @@
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::data::{Disks, Metadata, UserDirectories};
 
@@ -40,6 +41,12 @@ pub trait FileSystem {
     /// Returns true if the path exists and is a file
     fn is_file(&self, path: &Path) -> bool;
 
+    /// Returns true if the path is a symbolic link.
+    /// Symbolic links to directories are not followed when calculating directory sizes.
+    fn is_symlink(&self, _path: &Path) -> bool {
+        false
+    }
+
     /// Gets the children of a directory
     fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
 
@@ -65,6 +72,31 @@ pub trait FileSystem {
             "load_text_file_preview not implemented.".to_string(),
         ))
     }
+
+    /// Read up to `max_bytes` bytes from the beginning of a file.
+    /// Used to detect the MIME type of a file by its content.
+    fn read_file_header(&self, _path: &Path, _max_bytes: usize) -> io::Result<Vec<u8>> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "read_file_header not implemented.".to_string(),
+        ))
+    }
+
+    /// Read the entire content of a file.
+    fn read_file(&self, _path: &Path) -> io::Result<Vec<u8>> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "read_file not implemented.".to_string(),
+        ))
+    }
+
+    /// Replace the entire content of a file, creating the file if it does not exist.
+    fn write_file(&self, _path: &Path, _content: &[u8]) -> io::Result<()> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "write_file not implemented.".to_string(),
+        ))
+    }
 }
 
 impl std::fmt::Debug for dyn FileSystem + Send + Sync {
@@ -97,6 +129,10 @@ impl FileSystem for NativeFileSystem {
         path.is_file()
     }
 
+    fn is_symlink(&self, path: &Path) -> bool {
+        path.is_symlink()
+    }
+
     fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
         Ok(std::fs::read_dir(path)?
             .filter_map(Result::ok)
@@ -124,7 +160,54 @@ impl FileSystem for NativeFileSystem {
             buffer.push_str(&chars_read);
         }
 
-        Ok(buffer.to_string())
+        Ok(buffer)
+    }
+
+    fn read_file_header(&self, path: &Path, max_bytes: usize) -> io::Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(max_bytes);
+        std::fs::File::open(path)?
+            .take(max_bytes as u64)
+            .read_to_end(&mut buffer)?;
+
+        Ok(buffer)
+    }
+
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn write_file(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        // Counter making the names of temporary files unique within the process
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        // Write to a temporary file first and replace the file afterwards, so that other
+        // applications never read a partially written file. The name of the temporary
+        // file is unique, so that concurrent writers do not overwrite each other's file.
+        let mut tmp_name = std::ffi::OsString::from(".");
+        tmp_name.push(path.file_name().unwrap_or_default());
+        tmp_name.push(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let result = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(content)?;
+                // The content must be on disk before the file is replaced
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&tmp_path, path));
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+
+        result
     }
 
     fn get_disks(&self, canonicalize_paths: bool) -> Disks {
@@ -141,15 +224,25 @@ impl FileSystem for NativeFileSystem {
 
     fn user_dirs(&self, canonicalize_paths: bool) -> Option<UserDirectories> {
         if let Some(dirs) = directories::UserDirs::new() {
-            return Some(UserDirectories::new(
-                UserDirectories::canonicalize(Some(dirs.home_dir()), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.audio_dir(), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.desktop_dir(), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.document_dir(), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.download_dir(), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.picture_dir(), canonicalize_paths),
-                UserDirectories::canonicalize(dirs.video_dir(), canonicalize_paths),
-            ));
+            return Some(
+                UserDirectories::new(
+                    UserDirectories::canonicalize(Some(dirs.home_dir()), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.audio_dir(), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.desktop_dir(), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.document_dir(), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.download_dir(), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.picture_dir(), canonicalize_paths),
+                    UserDirectories::canonicalize(dirs.video_dir(), canonicalize_paths),
+                )
+                .with_template_dir(UserDirectories::canonicalize(
+                    dirs.template_dir(),
+                    canonicalize_paths,
+                ))
+                .with_public_dir(UserDirectories::canonicalize(
+                    dirs.public_dir(),
+                    canonicalize_paths,
+                )),
+            );
         }
 
         None
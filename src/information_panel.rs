@@ -1,6 +1,9 @@
 #![cfg(feature = "information_view")]
 
-use crate::{DirectoryEntry, FileDialog, FileSystem, NativeFileSystem};
+use crate::data::format_bytes;
+use crate::{
+    DirectoryEntry, DirectorySize, FileDialog, FileSystem, MimeDatabase, NativeFileSystem,
+};
 use chrono::{DateTime, Local};
 use egui::ahash::{HashMap, HashMapExt};
 use egui::{Direction, Layout, Ui, Vec2};
@@ -249,6 +252,7 @@ impl InformationPanel {
 
         // Display metadata in a grid format
         let width = file_dialog.config_mut().right_panel_width.unwrap_or(100.0) / 2.0;
+        let mime_database = file_dialog.config_mut().mime_database.clone();
 
         if let Some(item) = file_dialog.selected_entry() {
             // load file content and additional metadata if it's a new file
@@ -262,8 +266,21 @@ impl InformationPanel {
 
             ui.add_space(spacing);
 
+            let directory_size = if item.is_dir() {
+                file_dialog.directory_size(ui.ctx(), item.as_path())
+            } else {
+                None
+            };
+
             // show all metadata
-            self.display_meta_data(ui, file_dialog.get_window_id(), width, item);
+            self.display_meta_data(
+                ui,
+                file_dialog.get_window_id(),
+                width,
+                item,
+                directory_size,
+                &mime_database,
+            );
         }
     }
 
@@ -363,7 +380,15 @@ impl InformationPanel {
         }
     }
 
-    fn display_meta_data(&self, ui: &mut Ui, id: egui::Id, width: f32, item: &DirectoryEntry) {
+    fn display_meta_data(
+        &self,
+        ui: &mut Ui,
+        id: egui::Id,
+        width: f32,
+        item: &DirectoryEntry,
+        directory_size: Option<DirectorySize>,
+        mime_database: &MimeDatabase,
+    ) {
         egui::ScrollArea::vertical()
             .id_salt(id.with("meta_data_scroll"))
             .show(ui, |ui| {
@@ -377,7 +402,22 @@ impl InformationPanel {
                         ui.label(item.file_name().to_string());
                         ui.end_row();
 
-                        if let Some(size) = item.metadata().size {
+                        if let Some(mime_type) = item.mime_type() {
+                            ui.label("Type: ");
+                            match mime_database.description(mime_type) {
+                                Some(description) => {
+                                    ui.label(description).on_hover_text(mime_type.as_str())
+                                }
+                                None => ui.label(mime_type.as_str()),
+                            };
+                            ui.end_row();
+                        }
+
+                        if let Some(size) = directory_size {
+                            ui.label("File Size: ");
+                            ui.label(size.to_string());
+                            ui.end_row();
+                        } else if let Some(size) = item.metadata().size {
                             ui.label("File Size: ");
                             if item.is_file() {
                                 ui.label(format_bytes(size));
@@ -411,29 +451,3 @@ impl InformationPanel {
             });
     }
 }
-
-/// Formats a file size (in bytes) into a human-readable string (e.g., KB, MB).
-///
-/// # Arguments
-/// - `bytes`: The file size in bytes.
-///
-/// # Returns
-/// A string representing the file size in an appropriate unit.
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
-
-    if bytes >= TB {
-        format!("{:.2} TB", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{bytes} B")
-    }
-}
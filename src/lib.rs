@@ -7,7 +7,7 @@
 //!
 //! ### Features
 //!
-//! - Pick a file or a directory
+//! - Pick a file, a directory, or either one
 //! - Save a file (Prompt user for a destination path)
 //!   - Dialog to ask the user if the existing file should be overwritten
 //! - Pick multiple files and folders at once
@@ -19,9 +19,17 @@
 //! - Option to show or hide system files
 //! - Navigation buttons to open the parent or previous directories
 //! - Search for items in a directory
+//! - Type-ahead selection to jump to items by typing the beginning of their name
 //! - Add file filters the user can select from a dropdown
+//! - Quick filters to filter files by modification date and size
+//! - Optional recursive calculation of directory sizes in the background
+//! - Detect MIME types of files by extension and content
 //! - Shortcut for user directories (Home, Documents, ...) and system disks
-//! - Pin folders to the left sidebar
+//! - Reorder the user directories in the left sidebar using drag and drop
+//! - Pin folders to the left sidebar, reorder them and organize them in groups
+//! - Import the bookmarks of GTK and KDE file managers into the left sidebar
+//! - Optional list of the recently used files shared with other freedesktop applications
+//! - Profiles that remember the last directory and file filter for each purpose of the dialog
 //! - Manually edit the path via text
 //! - Virtual file system support
 //! - Customization highlights:
@@ -100,6 +108,8 @@
 //!
 //! - Set which areas and functions of the dialog are visible using `FileDialog::show_*` methods
 //! - Update the text labels that the dialog uses. See [Multilingual support](#multilingual-support)
+//! - Bundled translations for German, French and Spanish and loading of labels from Fluent or TOML files
+//! - Right-to-left layout for languages like Arabic and Hebrew using `FileDialog::right_to_left`
 //! - Customize file and folder icons using `FileDialog::set_file_icon`
 //!   (Currently only unicode is supported)
 //! - Customize keybindings used by the file dialog using `FileDialog::keybindings`.
@@ -158,41 +168,38 @@
 //!
 //! ### Multilingual support
 //! For desktop applications it is often necessary to offer different languages.
-//! While the dialog currently only offers English labels by default, the labels are
-//! fully customizable. This makes it possible to adapt the labels to different languages.
+//! The dialog uses English labels by default and bundles translations for German,
+//! French and Spanish, which can be selected with `FileDialogLabels::for_locale`.
+//! Labels missing in a translation fall back to English.
 //!
-//! The following example shows how the labels can be changed to display the file dialog in
-//! English or German. Checkout `examples/multilingual` for the full example.
+//! Other languages can be loaded from Fluent (`.ftl`) files at runtime using
+//! `FileDialogLabels::from_fluent`, or from TOML files using `FileDialogLabels::from_toml`
+//! with the `toml` feature. The labels can also be changed individually, as all fields of
+//! `FileDialogLabels` are public.
 //!
-//! ```
-//! use egui_file_dialog::{FileDialog, FileDialogLabels};
-//!
-//! enum Language {
-//!     English,
-//!     German,
-//! }
+//! Some labels contain placeholders like `{count}` or `{name}`, which are replaced when
+//! the label is displayed. Plural forms are written as `{count|One item|{count} items}`.
 //!
-//! fn get_labels_german() -> FileDialogLabels {
-//!     FileDialogLabels {
-//!         title_select_directory: "📁 Ordner Öffnen".to_string(),
-//!         title_select_file: "📂 Datei Öffnen".to_string(),
-//!         title_save_file: "📥 Datei Speichern".to_string(),
+//! For languages written from right to left, like Arabic or Hebrew, the layout of the dialog
+//! is mirrored. The direction is derived from `FileDialogLabels::locale` or can be set
+//! using `FileDialog::right_to_left`.
 //!
-//!         // ... See examples/multilingual for the other labels
+//! The following example shows how the language of the file dialog can be changed.
+//! Checkout `examples/multilingual` for the full example.
 //!
-//!         ..Default::default()
-//!     }
-//! }
+//! ```
+//! use egui_file_dialog::{FileDialog, FileDialogLabels};
 //!
 //! /// Updates the labels of the file dialog.
 //! /// Should be called every time the user selects a different language.
-//! fn update_labels(language: &Language, file_dialog: &mut FileDialog) {
-//!     *file_dialog.labels_mut() = match language {
-//!         // English labels are used by default
-//!         Language::English => FileDialogLabels::default(),
-//!         // Use custom labels for German
-//!         Language::German => get_labels_german(),
-//!     };
+//! fn update_labels(locale: &str, file_dialog: &mut FileDialog) {
+//!     *file_dialog.labels_mut() = FileDialogLabels::for_locale(locale);
+//! }
+//!
+//! /// Loads the labels of a language that is not bundled with the dialog.
+//! fn load_labels(ftl_file: &str) -> FileDialogLabels {
+//!     // Contains lines like: title-save-file = 📥 Datei Speichern
+//!     FileDialogLabels::from_fluent(ftl_file)
 //! }
 //! ```
 //!
@@ -214,6 +221,15 @@
 //! By default the feature `serde` is enabled, which implements `serde::Serialize` and
 //! `serde::Deserialize` for the objects to be saved. However, the objects can also be
 //! accessed without the feature enabled.
+//! The stored data is versioned, so data saved by previous versions of the file dialog can
+//! still be loaded. Missing fields are set to their default values and unknown fields are ignored.
+//!
+//! Without eframe, the optional `persistence` feature can be used to load and save the data
+//! as JSON or TOML file in the configuration directory of the user or at a custom path using
+//! `FileDialog::storage_file`. The file is saved automatically when the settings of the user
+//! change and when the dialog is closed.
+//!
+//! Multiple dialogs can share the same data using `FileDialog::shared_storage`.
 //!
 //! Checkout `examples/persistence` for an example.
 
@@ -228,12 +244,25 @@ mod file_system;
 /// Information panel showing the preview and metadata of the selected item
 pub mod information_panel;
 mod modals;
+#[cfg(feature = "persistence")]
+mod persistence;
+mod quick_filter_bar;
+#[cfg(test)]
+mod test_util;
 
 pub use config::{
-    FileDialogConfig, FileDialogKeyBindings, FileDialogLabels, IconFilter, KeyBinding, OpeningMode,
-    PinnedFolder, QuickAccess, QuickAccessPath,
+    format_label, FileDialogConfig, FileDialogKeyBindings, FileDialogLabels, FileFilterSpec,
+    IconFilter, KeyBinding, MimeDetection, MimeIconFilter, OpeningMode, PinnedFolder, Place,
+    QuickAccess, QuickAccessPath, SelectableEntries, SelectionConstraints,
+};
+pub use data::{
+    DirectoryEntry, DirectorySize, Disk, Disks, Metadata, MimeDatabase, MimeType, UserDirectories,
+    UserDirectory,
+};
+pub use file_dialog::{
+    DialogMode, DialogProfile, DialogState, FileDialog, FileDialogStorage, SharedStorage,
 };
-pub use data::{DirectoryEntry, Disk, Disks, Metadata, UserDirectories};
-pub use file_dialog::{DialogMode, DialogState, FileDialog, FileDialogStorage};
 
 pub use file_system::{FileSystem, NativeFileSystem};
+#[cfg(feature = "persistence")]
+pub use persistence::{StorageFile, StorageFormat};
@@ -54,19 +54,16 @@ impl FileDialogModal for OverwriteFileModal {
 
             ui.add_space(SECTION_SPACING);
 
-            // Used to wrap the path on a single line.
-            let mut job = egui::text::LayoutJob::single_section(
-                format!("'{}'", self.path.to_str().unwrap_or_default()),
-                egui::TextFormat::default(),
+            let name = self.path.file_name().map_or_else(
+                || self.path.to_string_lossy(),
+                |name| name.to_string_lossy(),
             );
 
-            job.wrap = egui::text::TextWrapping {
-                max_rows: 1,
-                ..Default::default()
-            };
-
-            ui.label(job);
-            ui.label(&config.labels.overwrite_file_modal_text);
+            ui.label(
+                config
+                    .labels
+                    .format(&config.labels.overwrite_file_modal_text, &[("name", &name)]),
+            );
 
             ui.add_space(SECTION_SPACING);
 
@@ -0,0 +1,171 @@
+//! Loading and saving of the `FileDialogStorage` to a file on disk.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{FileDialogStorage, FileSystem, NativeFileSystem};
+
+/// Name of the storage file inside the configuration directory, without the extension.
+const FILE_NAME: &str = "file_dialog";
+
+/// The format the `FileDialogStorage` is stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageFormat {
+    /// Store the data as JSON.
+    Json,
+    /// Store the data as TOML.
+    Toml,
+}
+
+impl StorageFormat {
+    /// Returns the file extension used for the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+/// A file the `FileDialogStorage` is loaded from and saved to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use egui_file_dialog::{FileDialog, StorageFile, StorageFormat};
+///
+/// # fn main() -> std::io::Result<()> {
+/// // Stores the data in `~/.config/my-app/file_dialog.toml` on Linux
+/// if let Some(file) = StorageFile::in_config_dir("my-app", StorageFormat::Toml) {
+///     let dialog = FileDialog::new().storage_file(file)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageFile {
+    path: PathBuf,
+    format: StorageFormat,
+}
+
+impl StorageFile {
+    /// Creates a new storage file at the given path.
+    pub fn new(path: impl Into<PathBuf>, format: StorageFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+        }
+    }
+
+    /// Creates a new storage file in the configuration directory of the given application,
+    /// like `$XDG_CONFIG_HOME/<application>/file_dialog.json` on Linux.
+    ///
+    /// Returns None if the configuration directory of the user could not be determined.
+    pub fn in_config_dir(application: &str, format: StorageFormat) -> Option<Self> {
+        let config_dir = directories::BaseDirs::new()?.config_dir().to_path_buf();
+        let path = config_dir
+            .join(application)
+            .join(FILE_NAME)
+            .with_extension(format.extension());
+
+        Some(Self::new(path, format))
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the format the data is stored in.
+    pub const fn format(&self) -> StorageFormat {
+        self.format
+    }
+
+    /// Loads the storage from the file. Data of previous versions is migrated
+    /// using `FileDialogStorage::migrate`.
+    pub fn load(&self) -> io::Result<FileDialogStorage> {
+        let content = std::fs::read_to_string(&self.path)?;
+
+        let mut storage: FileDialogStorage = match self.format {
+            StorageFormat::Json => serde_json::from_str(&content).map_err(io::Error::other)?,
+            StorageFormat::Toml => toml::from_str(&content).map_err(io::Error::other)?,
+        };
+
+        storage.migrate();
+
+        Ok(storage)
+    }
+
+    /// Saves the storage to the file, creating the parent directories if necessary.
+    ///
+    /// The data is written to a temporary file first, which then replaces the file,
+    /// so that the file is never left partially written.
+    pub fn save(&self, storage: &FileDialogStorage) -> io::Result<()> {
+        let content = match self.format {
+            StorageFormat::Json => {
+                serde_json::to_string_pretty(storage).map_err(io::Error::other)?
+            }
+            StorageFormat::Toml => toml::to_string_pretty(storage).map_err(io::Error::other)?,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        NativeFileSystem.write_file(&self.path, content.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+    use crate::{PinnedFolder, UserDirectory};
+
+    fn temp_file(dir: &Path, format: StorageFormat) -> StorageFile {
+        StorageFile::new(
+            dir.join(FILE_NAME).with_extension(format.extension()),
+            format,
+        )
+    }
+
+    fn storage() -> FileDialogStorage {
+        FileDialogStorage {
+            pinned_folders: vec![
+                PinnedFolder::from_path(PathBuf::from("/home/user/Music")).with_group("Media")
+            ],
+            show_hidden: true,
+            last_picked_dir: Some(PathBuf::from("/home/user")),
+            place_order: vec![UserDirectory::Downloads, UserDirectory::Home],
+            ..Default::default()
+        }
+    }
+
+    fn round_trip(format: StorageFormat) -> io::Result<()> {
+        let dir = TempDir::new("persistence-round-trip")?;
+        let file = temp_file(&dir, format);
+
+        file.save(&storage())?;
+        assert_eq!(file.load().ok(), Some(storage()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_json() -> io::Result<()> {
+        round_trip(StorageFormat::Json)
+    }
+
+    #[test]
+    fn round_trip_toml() -> io::Result<()> {
+        round_trip(StorageFormat::Toml)
+    }
+
+    #[test]
+    fn missing_file_is_an_error() -> io::Result<()> {
+        let dir = TempDir::new("persistence-missing")?;
+        assert!(temp_file(&dir, StorageFormat::Json).load().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use crate::data::date::Date;
+use crate::data::{format_bytes, parse_bytes, ModifiedRange, QuickFilter};
+use crate::FileDialogLabels;
+
+/// A bar to filter the directory content by modification date and size.
+/// The active filters are displayed as chips that can be removed individually.
+#[derive(Debug, Default)]
+pub struct QuickFilterBar {
+    /// If the bar with the filter inputs is currently visible.
+    open: bool,
+    /// The filter that is currently applied.
+    filter: QuickFilter,
+
+    /// Buffer holding the first day of the custom modification date range.
+    from_input: String,
+    /// Buffer holding the last day of the custom modification date range.
+    to_input: String,
+    /// Buffer holding the minimum size input.
+    min_size_input: String,
+    /// Buffer holding the maximum size input.
+    max_size_input: String,
+}
+
+impl QuickFilterBar {
+    /// Returns a mutable reference to the visibility of the bar.
+    /// Used to toggle the bar using a checkbox.
+    pub const fn open_mut(&mut self) -> &mut bool {
+        &mut self.open
+    }
+
+    /// Returns the filter that is currently applied.
+    pub const fn filter(&self) -> &QuickFilter {
+        &self.filter
+    }
+
+    /// Main update function of the bar. Should be called every frame.
+    ///
+    /// Returns true if the filter was changed.
+    pub fn update(&mut self, ui: &mut egui::Ui, labels: &FileDialogLabels, id: egui::Id) -> bool {
+        let previous = self.filter.clone();
+
+        if self.open {
+            ui.horizontal_wrapped(|ui| {
+                self.ui_update_modified_selection(ui, labels, id);
+                ui.separator();
+                self.ui_update_size_inputs(ui, labels);
+            });
+        }
+
+        if self.filter.is_active() {
+            ui.horizontal_wrapped(|ui| {
+                self.ui_update_chips(ui, labels);
+            });
+        }
+
+        self.filter != previous
+    }
+
+    fn ui_update_modified_selection(
+        &mut self,
+        ui: &mut egui::Ui,
+        labels: &FileDialogLabels,
+        id: egui::Id,
+    ) {
+        ui.label(&labels.quick_filter_modified);
+
+        let custom = ModifiedRange::Custom {
+            from: Date::parse(&self.from_input),
+            to: Date::parse(&self.to_input),
+        };
+
+        egui::containers::ComboBox::from_id_salt(id.with("quick_filter_modified"))
+            .selected_text(modified_range_text(self.filter.modified, labels))
+            .show_ui(ui, |ui| {
+                for range in [
+                    None,
+                    Some(ModifiedRange::Today),
+                    Some(ModifiedRange::LastSevenDays),
+                    Some(custom),
+                ] {
+                    let selected = match (self.filter.modified, range) {
+                        (
+                            Some(ModifiedRange::Custom { .. }),
+                            Some(ModifiedRange::Custom { .. }),
+                        ) => true,
+                        (current, range) => current == range,
+                    };
+
+                    if ui
+                        .selectable_label(selected, modified_range_text(range, labels))
+                        .clicked()
+                    {
+                        self.filter.modified = range;
+                    }
+                }
+            });
+
+        if !matches!(self.filter.modified, Some(ModifiedRange::Custom { .. })) {
+            return;
+        }
+
+        let from_changed = date_input(ui, &mut self.from_input);
+        ui.label("–");
+        let to_changed = date_input(ui, &mut self.to_input);
+
+        if from_changed || to_changed {
+            self.filter.modified = Some(ModifiedRange::Custom {
+                from: Date::parse(&self.from_input),
+                to: Date::parse(&self.to_input),
+            });
+        }
+    }
+
+    fn ui_update_size_inputs(&mut self, ui: &mut egui::Ui, labels: &FileDialogLabels) {
+        ui.label(&labels.quick_filter_size);
+
+        if size_input(ui, &mut self.min_size_input, &labels.quick_filter_min_size) {
+            self.filter.min_size = parse_bytes(&self.min_size_input);
+        }
+
+        ui.label("–");
+
+        if size_input(ui, &mut self.max_size_input, &labels.quick_filter_max_size) {
+            self.filter.max_size = parse_bytes(&self.max_size_input);
+        }
+    }
+
+    /// Updates the chips displaying the active filters.
+    /// Clicking a chip removes the filter.
+    fn ui_update_chips(&mut self, ui: &mut egui::Ui, labels: &FileDialogLabels) {
+        if let Some(range) = self.filter.modified {
+            let text = labels.format(
+                &labels.quick_filter_modified_chip,
+                &[("range", &modified_range_text(Some(range), labels))],
+            );
+
+            if ui_chip(ui, &text) {
+                self.filter.modified = None;
+            }
+        }
+
+        if let Some(min_size) = self.filter.min_size {
+            let text = labels.format(
+                &labels.quick_filter_min_size_chip,
+                &[("size", &format_bytes(min_size))],
+            );
+
+            if ui_chip(ui, &text) {
+                self.filter.min_size = None;
+                self.min_size_input.clear();
+            }
+        }
+
+        if let Some(max_size) = self.filter.max_size {
+            let text = labels.format(
+                &labels.quick_filter_max_size_chip,
+                &[("size", &format_bytes(max_size))],
+            );
+
+            if ui_chip(ui, &text) {
+                self.filter.max_size = None;
+                self.max_size_input.clear();
+            }
+        }
+    }
+}
+
+/// Returns the text describing the given modification date range.
+fn modified_range_text(range: Option<ModifiedRange>, labels: &FileDialogLabels) -> String {
+    match range {
+        None => labels.quick_filter_any_time.clone(),
+        Some(ModifiedRange::Today) => labels.quick_filter_today.clone(),
+        Some(ModifiedRange::LastSevenDays) => labels.quick_filter_last_seven_days.clone(),
+        Some(ModifiedRange::Custom {
+            from: None,
+            to: None,
+        }) => labels.quick_filter_custom.clone(),
+        Some(ModifiedRange::Custom { from, to }) => format!(
+            "{} – {}",
+            from.map(|d| d.to_string()).unwrap_or_default(),
+            to.map(|d| d.to_string()).unwrap_or_default()
+        ),
+    }
+}
+
+/// Updates a text input for a date.
+/// The text is highlighted if the input is not a valid date.
+///
+/// Returns true if the input changed.
+fn date_input(ui: &mut egui::Ui, input: &mut String) -> bool {
+    let valid = input.trim().is_empty() || Date::parse(input).is_some();
+    text_input(ui, input, "YYYY-MM-DD", valid)
+}
+
+/// Updates a text input for a file size.
+/// The text is highlighted if the input is not a valid size.
+///
+/// Returns true if the input changed.
+fn size_input(ui: &mut egui::Ui, input: &mut String, hint: &str) -> bool {
+    let valid = input.trim().is_empty() || parse_bytes(input).is_some();
+    text_input(ui, input, hint, valid)
+}
+
+fn text_input(ui: &mut egui::Ui, input: &mut String, hint: &str, valid: bool) -> bool {
+    let mut text_edit = egui::TextEdit::singleline(input)
+        .hint_text(hint)
+        .desired_width(80.0);
+
+    if !valid {
+        text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+    }
+
+    ui.add(text_edit).changed()
+}
+
+/// Updates a chip displaying an active filter.
+///
+/// Returns true if the chip was clicked and the filter should be removed.
+fn ui_chip(ui: &mut egui::Ui, text: &str) -> bool {
+    ui.add(
+        egui::Button::new(format!("{text}  ✖"))
+            .small()
+            .corner_radius(8.0),
+    )
+    .clicked()
+}
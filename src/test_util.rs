@@ -0,0 +1,67 @@
+//! Helpers shared by the unit tests.
+
+use std::io;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A temporary directory that is removed, including its content, when dropped.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates an empty temporary directory. The name is made unique, so that tests
+    /// running at the same time can use the same name.
+    pub fn new(name: &str) -> io::Result<Self> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "egui-file-dialog-{}-{}-{name}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path)?;
+
+        Ok(Self { path })
+    }
+
+    /// Creates a temporary directory containing the given entries.
+    /// Entries ending with `/` are created as folders, all others as files
+    /// containing their own name.
+    pub fn with_entries(name: &str, entries: &[&str]) -> io::Result<Self> {
+        let dir = Self::new(name)?;
+
+        for entry in entries {
+            if let Some(folder) = entry.strip_suffix('/') {
+                std::fs::create_dir_all(dir.join(folder))?;
+            } else {
+                std::fs::write(dir.join(entry), entry)?;
+            }
+        }
+
+        Ok(dir)
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}